@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_add_price_manager --accountId $CONTRACT_NAME "{ \"account_id\": \"muzikant.testnet\" }" --gas 300000000000000
+near view $CONTRACT_NAME loan_price_managers "{}"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_borrow_limit "{}"
+near view $CONTRACT_NAME loan_borrow_limit_of "{ \"account_id\": \"muzikant.testnet\" }"
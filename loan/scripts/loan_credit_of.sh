@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_credit_of "{ \"account_id\": \"muzikant.testnet\" }"
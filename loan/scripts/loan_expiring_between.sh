@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+FROM=$(($(date +%s) * 1000))
+TO=$(($FROM + 86400000))
+near view $CONTRACT_NAME loan_expiring_between "{ \"from\": $FROM, \"to\": $TO, \"from_index\": \"0\", \"limit\": 20 }"
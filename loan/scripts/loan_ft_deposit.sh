@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+FT_CONTRACT="usdc.fakes.testnet"
+AMOUNT="1000000"
+near call $FT_CONTRACT ft_transfer_call --accountId $ACCOUNT_ID "{ \"receiver_id\": \"$CONTRACT_NAME\", \"amount\": \"$AMOUNT\", \"msg\": \"{\\\"action\\\": \\\"deposit\\\"}\" }" --depositYocto 1 --gas 300000000000000
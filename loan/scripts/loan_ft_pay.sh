@@ -0,0 +1,7 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+FT_CONTRACT="usdc.fakes.testnet"
+TOKEN_ID="4"
+AMOUNT="872000"
+near call $FT_CONTRACT ft_transfer_call --accountId $ACCOUNT_ID "{ \"receiver_id\": \"$CONTRACT_NAME\", \"amount\": \"$AMOUNT\", \"msg\": \"{\\\"action\\\": \\\"pay\\\", \\\"token_id\\\": \\\"$TOKEN_ID\\\", \\\"contract_id\\\": \\\"$NFT_CONTRACT\\\"}\" }" --depositYocto 1 --gas 300000000000000
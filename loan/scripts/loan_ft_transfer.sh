@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+RECEIVER_ID="muzikant2.testnet"
+AMOUNT="500000000000000000000000"
+near call $CONTRACT_NAME ft_transfer --accountId $ACCOUNT_ID "{ \"receiver_id\": \"$RECEIVER_ID\", \"amount\": \"$AMOUNT\" }" --depositYocto 1 --gas 300000000000000
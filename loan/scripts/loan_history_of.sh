@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near view $CONTRACT_NAME loan_history_of "{ \"account_id\": \"muzikant.testnet\", \"from_index\": \"0\", \"limit\": 20 }"
+near view $CONTRACT_NAME loan_history_by_contract "{ \"contract_id\": \"$NFT_CONTRACT\", \"from_index\": \"0\", \"limit\": 20 }"
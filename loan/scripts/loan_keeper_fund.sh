@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_keeper_fund --accountId $CONTRACT_NAME "{}" --deposit 1 --gas 300000000000000
+near view $CONTRACT_NAME loan_keeper_reserve "{}"
@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_keeper_liquidate --accountId muzikant.testnet "{ \"limit\": 10 }" --gas 300000000000000
@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+near view $CONTRACT_NAME loan_locks_of "{ \"account_id\": \"$ACCOUNT_ID\" }"
+near view $CONTRACT_NAME loan_locked_balance_of "{ \"account_id\": \"$ACCOUNT_ID\" }"
@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+TOKEN_ID="11"
+near view $CONTRACT_NAME loan_health_by_id "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\" }"
+near call $CONTRACT_NAME loan_margin_call --accountId muzikant.testnet "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\" }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+PUBLIC_KEY="ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+near call $CONTRACT_NAME loan_nft_add_price_key --accountId $CONTRACT_NAME "{ \"reporter_id\": \"muzikant.testnet\", \"public_key\": \"$PUBLIC_KEY\" }" --gas 300000000000000
@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+TOKEN_ID="11"
+near view $CONTRACT_NAME loan_nft_appraisal "{ \"contract_id\": \"$NFT_CONTRACT\", \"token_id\": \"$TOKEN_ID\" }"
+near view $CONTRACT_NAME loan_nft_appraisals "{ \"from_index\": \"0\", \"limit\": 20 }"
@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+TOKEN_ID="6"
+PRICE="1500000000000000000000000"
+near call $CONTRACT_NAME loan_nft_buyout_list --accountId $ACCOUNT_ID "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\", \"price\": \"$PRICE\" }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+TOKEN_ID="6"
+near view $CONTRACT_NAME loan_nft_buyout_price "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\" }"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near view $CONTRACT_NAME loan_nft_price_history "{ \"contract_id\": \"$NFT_CONTRACT\", \"from_index\": \"0\", \"limit\": 20 }"
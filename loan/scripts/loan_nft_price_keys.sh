@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_nft_price_keys "{}"
+near view $CONTRACT_NAME loan_quote_max_age "{}"
@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+TOKEN_ID="11"
+near call $CONTRACT_NAME loan_nft_set_appraisal --accountId muzikant.testnet "{ \"contract_id\": \"$NFT_CONTRACT\", \"token_id\": \"$TOKEN_ID\", \"price\": \"50000000000000000000000000\", \"percent\": 40 }" --gas 300000000000000
@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+ORACLE_CONTRACT="dev-1655000000000-00000000000000"
+near call $CONTRACT_NAME loan_nft_set_oracle --accountId $CONTRACT_NAME "{ \"contract_id\": \"$NFT_CONTRACT\", \"oracle_id\": \"$ORACLE_CONTRACT\", \"max_age\": 600000 }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near call $CONTRACT_NAME loan_nft_set_reporters --accountId $CONTRACT_NAME "{ \"contract_id\": \"$NFT_CONTRACT\", \"reporters\": [\"muzikant.testnet\", \"$CONTRACT_NAME\"], \"quorum\": 2, \"max_deviation\": 20 }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near call $CONTRACT_NAME loan_nft_set_tiers --accountId $CONTRACT_NAME "{ \"contract_id\": \"$NFT_CONTRACT\", \"tiers\": [{ \"name\": \"grail\", \"rule\": { \"tokens\": [\"1\", \"7\"] }, \"price\": \"20000000000000000000000000\", \"percent\": 40 }, { \"name\": \"genesis\", \"rule\": { \"range\": { \"from\": 1, \"to\": 100 } }, \"price\": \"5000000000000000000000000\", \"percent\": 30 }, { \"name\": \"legendary\", \"rule\": { \"attribute\": { \"key\": \"rarity\", \"value\": \"legendary\" } }, \"price\": \"10000000000000000000000000\", \"percent\": 35 }] }" --gas 300000000000000
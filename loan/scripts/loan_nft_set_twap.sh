@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near call $CONTRACT_NAME loan_nft_set_twap --accountId $CONTRACT_NAME "{ \"contract_id\": \"$NFT_CONTRACT\", \"window\": 86400000 }" --gas 300000000000000
@@ -0,0 +1,8 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+PUBLIC_KEY="ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp"
+# base64 ed25519 signature of borsh(loan_id, pool_id, contract_id, price, timestamp, nonce)
+SIGNATURE=""
+TIMESTAMP="1655000000000"
+near call $CONTRACT_NAME loan_nft_submit_price --accountId muzikant.testnet "{ \"quote\": { \"contract_id\": \"$NFT_CONTRACT\", \"price\": \"2000000000000000000000000\", \"timestamp\": $TIMESTAMP, \"nonce\": 1, \"public_key\": \"$PUBLIC_KEY\", \"signature\": \"$SIGNATURE\" } }" --gas 300000000000000
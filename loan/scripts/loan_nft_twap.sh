@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near view $CONTRACT_NAME loan_nft_twap_window "{ \"contract_id\": \"$NFT_CONTRACT\" }"
+near view $CONTRACT_NAME loan_nft_twap "{ \"contract_id\": \"$NFT_CONTRACT\" }"
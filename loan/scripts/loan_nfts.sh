@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near view $CONTRACT_NAME loan_nfts "{ \"from_index\": \"0\", \"limit\": 20, \"filter\": { \"contract_id\": \"$NFT_CONTRACT\", \"status\": \"active\" } }"
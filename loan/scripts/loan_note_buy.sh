@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant2.testnet"
+TOKEN_ID="6"
+near call $CONTRACT_NAME loan_note_buy --accountId $ACCOUNT_ID "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\" }" --amount "0.9" --gas 300000000000000
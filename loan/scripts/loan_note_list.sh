@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+TOKEN_ID="6"
+PRICE="900000000000000000000000"
+near call $CONTRACT_NAME loan_note_list --accountId $CONTRACT_NAME "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\", \"price\": \"$PRICE\" }" --gas 300000000000000
+near view $CONTRACT_NAME loan_note_price "{ \"token_id\": \"$TOKEN_ID\", \"contract_id\": \"$NFT_CONTRACT\" }"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+TOKEN_ID="6"
+near view $CONTRACT_NAME nft_token "{ \"token_id\": \"$NFT_CONTRACT||$TOKEN_ID\" }"
@@ -0,0 +1,6 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant2.testnet"
+RECEIVER_ID="muzikant.testnet"
+TOKEN_ID="6"
+near call $CONTRACT_NAME nft_transfer --accountId $ACCOUNT_ID "{ \"receiver_id\": \"$RECEIVER_ID\", \"token_id\": \"$NFT_CONTRACT||$TOKEN_ID\" }" --depositYocto 1 --gas 300000000000000
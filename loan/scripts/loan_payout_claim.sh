@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+near call $CONTRACT_NAME loan_payout_claim --accountId $ACCOUNT_ID "{ }" --gas 300000000000000
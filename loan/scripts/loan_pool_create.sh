@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+POOL_ID="blue-chip"
+OWNER_ID="muzikant.testnet"
+near call $CONTRACT_NAME loan_pool_create --accountId $CONTRACT_NAME "{ \"pool_id\": \"$POOL_ID\", \"owner_id\": \"$OWNER_ID\", \"commission\": 5 }" --gas 300000000000000
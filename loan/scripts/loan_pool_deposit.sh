@@ -0,0 +1,5 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+POOL_ID="blue-chip"
+near call $CONTRACT_NAME loan_deposit --accountId $ACCOUNT_ID "{ \"pool_id\": \"$POOL_ID\" }" --amount "1"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_reserve "{}"
+near view $CONTRACT_NAME loan_lendable_balance "{}"
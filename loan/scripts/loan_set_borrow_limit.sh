@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_borrow_limit --accountId $CONTRACT_NAME "{ \"max_principal\": \"10000000000000000000000000\", \"max_loans\": 5 }" --gas 300000000000000
+near call $CONTRACT_NAME loan_set_account_borrow_limit --accountId $CONTRACT_NAME "{ \"account_id\": \"muzikant.testnet\", \"max_loans\": 10 }" --gas 300000000000000
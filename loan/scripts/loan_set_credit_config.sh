@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_credit_config --accountId $CONTRACT_NAME "{ \"max_ltv_shift\": 10, \"max_fee_shift\": 30, \"full_score\": 5, \"max_defaults\": 2, \"min_principal\": \"1000000000000000000000000\" }" --gas 300000000000000
+near view $CONTRACT_NAME loan_credit_config "{}"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+FT_CONTRACT="usdc.fakes.testnet"
+near call $CONTRACT_NAME loan_set_currency --accountId $CONTRACT_NAME "{ \"currency\": \"$FT_CONTRACT\" }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_health_thresholds --accountId $CONTRACT_NAME "{ \"maintenance_health\": 120, \"liquidation_health\": 100 }" --gas 300000000000000
+near view $CONTRACT_NAME loan_health_thresholds "{}"
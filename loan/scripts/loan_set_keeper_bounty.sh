@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_keeper_bounty --accountId $CONTRACT_NAME "{ \"bounty\": \"10000000000000000000000\" }" --gas 300000000000000
+near view $CONTRACT_NAME loan_keeper_bounty "{}"
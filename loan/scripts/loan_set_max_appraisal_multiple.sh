@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_max_appraisal_multiple --accountId $CONTRACT_NAME "{ \"max_multiple\": 5 }" --gas 300000000000000
+near view $CONTRACT_NAME loan_max_appraisal_multiple "{}"
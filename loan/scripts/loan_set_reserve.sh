@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_reserve --accountId $CONTRACT_NAME "{ \"reserve_ratio\": 10, \"max_utilization\": 80 }" --gas 300000000000000
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_terms --accountId $CONTRACT_NAME "{ \"duration\": 604800000, \"grace_period\": 86400000, \"penalty_rate\": 5 }" --gas 300000000000000
+near view $CONTRACT_NAME loan_terms "{}"
@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_tranche_config --accountId $CONTRACT_NAME "{ \"senior_max_apr\": 8, \"junior_fee_multiplier\": 2 }" --gas 300000000000000
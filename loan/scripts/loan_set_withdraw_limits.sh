@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near call $CONTRACT_NAME loan_set_withdraw_limits --accountId $CONTRACT_NAME "{ \"unbonding_epochs\": 2, \"withdraw_cap\": 20, \"withdraw_window\": 86400000 }" --gas 300000000000000
@@ -0,0 +1,8 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+RECEIVER_ID="muzikant2.testnet"
+AMOUNT="500000000000000000000000"
+POOL_ID="nft"
+near call $CONTRACT_NAME loan_share_transfer --accountId $ACCOUNT_ID "{ \"receiver_id\": \"$RECEIVER_ID\", \"amount\": \"$AMOUNT\", \"pool_id\": \"$POOL_ID\" }" --depositYocto 1 --gas 300000000000000
+near view $CONTRACT_NAME loan_shares_of "{ \"account_id\": \"$RECEIVER_ID\", \"pool_id\": \"$POOL_ID\" }"
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_tranche_balance "{ \"tranche\": \"senior\" }"
+near view $CONTRACT_NAME loan_tranche_balance "{ \"tranche\": \"junior\" }"
@@ -0,0 +1,3 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_unhealthy "{ \"from_index\": \"0\", \"limit\": 20 }"
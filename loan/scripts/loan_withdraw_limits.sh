@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+near view $CONTRACT_NAME loan_withdraw_limits "{}"
+near view $CONTRACT_NAME loan_withdraw_limit_left "{}"
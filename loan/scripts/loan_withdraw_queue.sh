@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+ACCOUNT_ID="muzikant.testnet"
+near call $CONTRACT_NAME loan_withdraw_queue --accountId $ACCOUNT_ID "{ \"amount\": \"1000000000000000000000000\" }" --gas 300000000000000
@@ -0,0 +1,25 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::base::{ContractId, TokenId};
+use crate::meta::JsonAppraisal;
+
+/// Hand appraised value of a token, `percent` falls back to the collection one.
+/// `price` is capped by `max_appraisal_multiple` of the collection price
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Appraisal {
+  pub price: Balance,
+  pub percent: Option<u64>,
+}
+
+pub trait LoanFactoryAppraisal {
+  fn loan_add_price_manager(&mut self, account_id: AccountId);
+  fn loan_remove_price_manager(&mut self, account_id: AccountId);
+  fn loan_nft_set_appraisal(&mut self, contract_id: ContractId, token_id: TokenId, price: Option<U128>, percent: Option<u64>);
+  fn loan_set_max_appraisal_multiple(&mut self, max_multiple: u64);
+
+  fn loan_price_managers(&self) -> Vec<AccountId>;
+  fn loan_nft_appraisal(&self, contract_id: ContractId, token_id: TokenId) -> Option<JsonAppraisal>;
+  fn loan_nft_appraisals(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonAppraisal>;
+  fn loan_max_appraisal_multiple(&self) -> u64;
+}
@@ -0,0 +1,116 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::appraisal::{LoanFactoryAppraisal, Appraisal};
+use crate::meta::JsonAppraisal;
+use crate::event::{LoanPriceManagerUpdate, LoanNftAppraisal, LoanAppraisalCapUpdate};
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, AccountId};
+
+impl LoanFactoryAppraisal for LoanFactory {
+  fn loan_add_price_manager(&mut self, account_id: AccountId) {
+    self.assert_owner();
+
+    self.price_managers.insert(&account_id);
+
+    LoanPriceManagerUpdate {
+      account_id: &account_id,
+      enabled: &true,
+    }.emit();
+  }
+
+  fn loan_remove_price_manager(&mut self, account_id: AccountId) {
+    self.assert_owner();
+
+    self.price_managers.remove(&account_id);
+
+    LoanPriceManagerUpdate {
+      account_id: &account_id,
+      enabled: &false,
+    }.emit();
+  }
+
+  fn loan_nft_set_appraisal(&mut self, contract_id: ContractId, token_id: TokenId, price: Option<U128>, percent: Option<u64>) {
+    self.assert_price_manager();
+
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    match price {
+      Some(price) => {
+        if price.0 < 1 {
+          env::panic_str("Invalid price");
+        }
+        if percent.is_some_and(|percent| !(1..=50).contains(&percent)) {
+          env::panic_str("Percent should be from 1 to 50");
+        }
+        if price.0 > self.internal_max_appraisal(&contract_id) {
+          env::panic_str(&format!("Appraisal is above {} collection prices", self.max_appraisal_multiple));
+        }
+
+        self.appraisal_by_nft.insert(&contract_token_id, &Appraisal {
+          price: price.0,
+          percent,
+        });
+      }
+      None => {
+        self.appraisal_by_nft.remove(&contract_token_id);
+      }
+    }
+
+    LoanNftAppraisal {
+      contract_id: &contract_id,
+      token_id: &token_id,
+      price: price.as_ref(),
+      percent: percent.as_ref(),
+    }.emit();
+  }
+
+  fn loan_set_max_appraisal_multiple(&mut self, max_multiple: u64) {
+    self.assert_owner();
+
+    if max_multiple < 1 {
+      env::panic_str("Min multiple is 1");
+    }
+
+    self.max_appraisal_multiple = max_multiple;
+
+    LoanAppraisalCapUpdate {
+      max_multiple: &max_multiple,
+    }.emit();
+  }
+
+  fn loan_price_managers(&self) -> Vec<AccountId> {
+    self.price_managers.to_vec()
+  }
+
+  fn loan_nft_appraisal(&self, contract_id: ContractId, token_id: TokenId) -> Option<JsonAppraisal> {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.appraisal_by_nft
+      .get(&contract_token_id)
+      .map(|appraisal| self.enum_get_appraisal(&contract_token_id, appraisal))
+  }
+
+  fn loan_nft_appraisals(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonAppraisal> {
+    if self.appraisal_by_nft.is_empty() {
+      return vec![];
+    }
+
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+    require!(
+      self.appraisal_by_nft.len() as u128 > start_index,
+      "Out of bounds, please use a smaller from_index."
+    );
+
+    self.appraisal_by_nft
+      .iter()
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|(contract_token_id, appraisal)| self.enum_get_appraisal(&contract_token_id, appraisal))
+      .collect()
+  }
+
+  fn loan_max_appraisal_multiple(&self) -> u64 {
+    self.max_appraisal_multiple
+  }
+}
@@ -0,0 +1,39 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::appraisal::Appraisal;
+use crate::meta::JsonAppraisal;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+
+impl LoanFactory {
+  pub(crate) fn assert_price_manager(&self) {
+    let account_id = env::predecessor_account_id();
+
+    if account_id != self.owner_id && !self.price_managers.contains(&account_id) {
+      env::panic_str("Unauthorized");
+    }
+  }
+
+  /// Appraised price and percent of the token, takes precedence over tiers and the collection price.
+  /// The cap is applied again on use, the collection price can drop after the appraisal
+  pub(crate) fn internal_appraisal_of(&self, contract_id: &ContractId, token_id: &TokenId) -> Option<(Balance, u64)> {
+    let appraisal = self.appraisal_by_nft.get(&self.internal_get_token_id(contract_id, token_id))?;
+    let percent = appraisal.percent.or_else(|| self.percent_by_contract.get(contract_id))?;
+
+    Some((appraisal.price.min(self.internal_max_appraisal(contract_id)), percent))
+  }
+
+  pub(crate) fn internal_max_appraisal(&self, contract_id: &ContractId) -> Balance {
+    self.internal_loan_price(contract_id) * self.max_appraisal_multiple as u128
+  }
+
+  pub(crate) fn enum_get_appraisal(&self, contract_token_id: &TokenId, appraisal: Appraisal) -> JsonAppraisal {
+    let arr = contract_token_id.split("||").collect::<Vec<&str>>();
+
+    JsonAppraisal {
+      token_id: arr[1].to_string(),
+      contract_id: AccountId::new_unchecked(arr[0].to_string()),
+      price: U128::from(appraisal.price),
+      percent: appraisal.percent,
+    }
+  }
+}
@@ -0,0 +1,5 @@
+mod appraisal_impl;
+mod appraisal;
+mod internal;
+
+pub use self::appraisal::{LoanFactoryAppraisal, Appraisal};
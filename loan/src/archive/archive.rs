@@ -0,0 +1,57 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::base::{ContractId, TokenId};
+use crate::meta::JsonLoanRecord;
+use crate::terms::LoanTerms;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum LoanOutcome {
+  Open,
+  Repaid,
+  Defaulted,
+  Liquidated,
+  BoughtOut,
+  /// the principal transfer to the borrower failed
+  Cancelled,
+}
+
+/// Terms are written when the loan is taken, payments and the outcome when it is closed
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LoanRecord {
+  pub borrower_id: AccountId,
+  pub contract_id: ContractId,
+  pub token_id: TokenId,
+  pub price: Balance,
+  pub percent: u64,
+  pub terms: LoanTerms,
+  pub started_at: u64,
+  pub expire_date: u64,
+  pub repaid: Balance,
+  pub closed_at: Option<u64>,
+  pub outcome: LoanOutcome,
+}
+
+/// Stored records are converted to the current layout when they are read,
+/// a later record layout is added as the next variant
+#[derive(BorshDeserialize, BorshSerialize)]
+pub enum VersionedLoanRecord {
+  V1(LoanRecord),
+}
+
+impl From<VersionedLoanRecord> for LoanRecord {
+  fn from(record: VersionedLoanRecord) -> Self {
+    match record {
+      VersionedLoanRecord::V1(record) => record,
+    }
+  }
+}
+
+pub trait LoanFactoryArchive {
+  fn loan_record(&self, loan_id: u64) -> Option<JsonLoanRecord>;
+  fn loan_history_of(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanRecord>;
+  fn loan_history_by_contract(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanRecord>;
+}
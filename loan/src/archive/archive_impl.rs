@@ -0,0 +1,25 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::archive::LoanFactoryArchive;
+use crate::meta::JsonLoanRecord;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+impl LoanFactoryArchive for LoanFactory {
+  fn loan_record(&self, loan_id: u64) -> Option<JsonLoanRecord> {
+    self.internal_loan_record(loan_id).map(|record| self.enum_get_loan_record(loan_id, record))
+  }
+
+  fn loan_history_of(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanRecord> {
+    match self.loan_history_by_account.get(&account_id) {
+      Some(loan_ids) => self.internal_loan_records(&loan_ids, from_index, limit),
+      None => vec![],
+    }
+  }
+
+  fn loan_history_by_contract(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanRecord> {
+    match self.loan_history_by_contract.get(&contract_id) {
+      Some(loan_ids) => self.internal_loan_records(&loan_ids, from_index, limit),
+      None => vec![],
+    }
+  }
+}
@@ -0,0 +1,116 @@
+use crate::base::{LoanFactory, TokenId};
+use crate::archive::{LoanOutcome, LoanRecord, VersionedLoanRecord};
+use crate::meta::JsonLoanRecord;
+use crate::utils::date_now;
+use crate::{pool_storage_key, StorageKey};
+use near_sdk::collections::Vector;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require, Balance};
+
+impl LoanFactory {
+  /// Archives the open `record` under `loan_id` given by the contract
+  pub(crate) fn internal_open_loan_record(&mut self, loan_id: u64, record: LoanRecord) -> u64 {
+    let contract_token_id = self.internal_get_token_id(&record.contract_id, &record.token_id);
+
+    let mut account_loans = self.loan_history_by_account.get(&record.borrower_id).unwrap_or_else(|| {
+      Vector::new(pool_storage_key(self.pool_id.as_ref(), StorageKey::LoanHistoryPerAccount {
+        account_hash: env::sha256(record.borrower_id.as_bytes()),
+      }))
+    });
+    account_loans.push(&loan_id);
+    self.loan_history_by_account.insert(&record.borrower_id, &account_loans);
+
+    let mut contract_loans = self.loan_history_by_contract.get(&record.contract_id).unwrap_or_else(|| {
+      Vector::new(pool_storage_key(self.pool_id.as_ref(), StorageKey::LoanHistoryPerContract {
+        contract_hash: env::sha256(record.contract_id.as_bytes()),
+      }))
+    });
+    contract_loans.push(&loan_id);
+    self.loan_history_by_contract.insert(&record.contract_id, &contract_loans);
+
+    self.loan_id_by_nft.insert(&contract_token_id, &loan_id);
+    self.internal_save_loan_record(loan_id, record);
+
+    loan_id
+  }
+
+  /// Repaid loans keep their id until the nft is claimed, other outcomes release it at once
+  pub(crate) fn internal_close_loan_record(&mut self, contract_token_id: &TokenId, outcome: LoanOutcome, repaid: Balance) {
+    let loan_id = match self.loan_id_by_nft.get(contract_token_id) {
+      Some(loan_id) => loan_id,
+      // loans taken before the archive existed
+      None => return,
+    };
+    let mut record = self.internal_loan_record(loan_id).expect("Not found loan record");
+
+    record.repaid = repaid;
+    record.closed_at = Some(date_now());
+    record.outcome = outcome;
+
+    self.internal_update_credit(&record, true);
+    self.internal_save_loan_record(loan_id, record);
+
+    if outcome != LoanOutcome::Repaid {
+      self.loan_id_by_nft.remove(contract_token_id);
+    }
+  }
+
+  /// Payment transfer failed, the loan is open again
+  pub(crate) fn internal_reopen_loan_record(&mut self, contract_token_id: &TokenId) {
+    if let Some(loan_id) = self.loan_id_by_nft.get(contract_token_id) {
+      let mut record = self.internal_loan_record(loan_id).expect("Not found loan record");
+
+      self.internal_update_credit(&record, false);
+
+      record.repaid = 0;
+      record.closed_at = None;
+      record.outcome = LoanOutcome::Open;
+
+      self.internal_save_loan_record(loan_id, record);
+    }
+  }
+
+  /// Loans taken before the upgrade have no record, their terms fall back to `internal_loan_terms`
+  pub(crate) fn internal_loan_record(&self, loan_id: u64) -> Option<LoanRecord> {
+    self.loan_records.get(&loan_id).map(LoanRecord::from)
+  }
+
+  pub(crate) fn internal_save_loan_record(&mut self, loan_id: u64, record: LoanRecord) {
+    self.loan_records.insert(&loan_id, &VersionedLoanRecord::V1(record));
+  }
+
+  pub(crate) fn internal_loan_records(&self, loan_ids: &Vector<u64>, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanRecord> {
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+    require!(
+      loan_ids.len() as u128 > start_index,
+      "Out of bounds, please use a smaller from_index."
+    );
+
+    loan_ids
+      .iter()
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|loan_id| self.enum_get_loan_record(loan_id, self.internal_loan_record(loan_id).expect("Not found loan record")))
+      .collect()
+  }
+
+  pub(crate) fn enum_get_loan_record(&self, loan_id: u64, record: LoanRecord) -> JsonLoanRecord {
+    JsonLoanRecord {
+      loan_id,
+      borrower_id: record.borrower_id,
+      contract_id: record.contract_id,
+      token_id: record.token_id,
+      price: U128::from(record.price),
+      percent: record.percent,
+      terms: self.enum_get_loan_terms(&record.terms),
+      started_at: record.started_at,
+      expire_date: record.expire_date,
+      repaid: U128::from(record.repaid),
+      closed_at: record.closed_at,
+      outcome: record.outcome,
+      pool_id: self.pool_id.clone(),
+    }
+  }
+}
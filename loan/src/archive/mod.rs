@@ -0,0 +1,5 @@
+mod archive_impl;
+mod archive;
+mod internal;
+
+pub use self::archive::{LoanFactoryArchive, LoanOutcome, LoanRecord, VersionedLoanRecord};
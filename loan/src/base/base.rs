@@ -1,9 +1,38 @@
 use near_sdk::{Balance, AccountId, ext_contract};
 use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
 use crate::meta::JsonLoan;
 
 pub type TokenId = String;
 pub type ContractId = AccountId;
+pub type PoolId = String;
+
+/// Loan taken by `internal_lend_nft`, booked by `loan_resolve_nft` once the nft is received
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LoanRequest {
+  pub receiver_id: AccountId,
+  pub contract_id: ContractId,
+  pub token_id: TokenId,
+  pub contract_token_id: TokenId,
+  pub price: U128,
+  pub percent: u64,
+  pub tier: Option<String>,
+}
+
+/// Repayment booked by `internal_nft_pay`, undone by `on_transfer_nft_pay` when the transfer fails.
+/// `fee` is the part of the pool, `penalty` the part of the keeper reserve
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayRollback {
+  pub account_id: AccountId,
+  pub contract_id: ContractId,
+  pub token_id: TokenId,
+  pub contract_token_id: TokenId,
+  pub principal: U128,
+  pub fee: U128,
+  pub penalty: U128,
+}
 
 pub trait LoanFactoryCore {
     fn loan_nft(&mut self, token_id: TokenId, contract_id: ContractId);
@@ -25,6 +54,6 @@ pub trait LoanFactoryCore {
 }
 
 pub trait LoanFactoryResolver {
-    fn loan_resolve_nft(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId, price: Balance, percent: u64);
+    fn loan_resolve_nft(&mut self, loan_id: u64, request: LoanRequest);
     fn loan_resolve_nft_claim(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId);
 }
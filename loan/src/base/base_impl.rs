@@ -1,13 +1,29 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedSet, TreeMap};
+use near_sdk::collections::{LookupMap, UnorderedMap, UnorderedSet, TreeMap, Vector};
 use near_sdk::json_types::U128;
-use near_sdk::{env, ext_contract, log, require, AccountId, Balance, Gas, IntoStorageKey, PromiseOrValue, PromiseResult, StorageUsage, Promise, is_promise_success};
+use near_sdk::{env, ext_contract, log, require, AccountId, Balance, Gas, IntoStorageKey, PromiseOrValue, PromiseResult, PublicKey, StorageUsage, Promise, is_promise_success};
 use crate::base::{LoanFactoryCore, LoanFactoryResolver};
-use crate::base::base::{ContractId, TokenId};
+use crate::base::base::{ContractId, LoanRequest, PayRollback, PoolId, TokenId};
 use std::collections::HashMap;
 use crate::utils::date_now;
 use crate::meta::JsonLoan;
 use crate::event::{LoanWhitelistUpdatePrice, LoanNftClaimExpired, LoanNftClaim, LoanNft, LoanNftPay};
+use near_contract_standards::non_fungible_token::NonFungibleToken;
+use crate::tranche::Tranche;
+use crate::queue::WithdrawRequest;
+use crate::lock::DepositLock;
+use crate::cooldown::Unbonding;
+use crate::whitelist::ExposureCap;
+use crate::limit::BorrowLimit;
+use crate::oracle::{ext_oracle, OracleConfig};
+use crate::feed::PriceFeed;
+use crate::quote::PriceKey;
+use crate::history::PriceHistory;
+use crate::tier::{ext_nft_token, Tier, TierMatch};
+use crate::appraisal::Appraisal;
+use crate::archive::{LoanOutcome, LoanRecord, VersionedLoanRecord};
+use crate::credit::{CreditConfig, CreditHistory};
+use crate::{pool_storage_key, StorageKey};
 
 const CALLBACK_ON_RESOLVE_NFT: Gas = Gas(50_000_000_000_000);
 const CALLBACK_ON_PAY: Gas = Gas(20_000_000_000_000);
@@ -15,16 +31,26 @@ const CALLBACK_ON_PAY: Gas = Gas(20_000_000_000_000);
 const GAS_FOR_LOAN_NFT: Gas = Gas(60_000_000_000_000);
 const GAS_FOR_LOAN_CLAIM_NFT: Gas = Gas(60_000_000_000_000);
 const GAS_FOR_NFT_TRANSFER: Gas = Gas(18_000_000_000_000);
+const GAS_FOR_ORACLE_PRICE: Gas = Gas(10_000_000_000_000);
+const GAS_FOR_LOAN_ORACLE: Gas = Gas(20_000_000_000_000);
+const GAS_FOR_NFT_TOKEN: Gas = Gas(10_000_000_000_000);
+const GAS_FOR_LOAN_TIER: Gas = Gas(20_000_000_000_000);
 const NO_DEPOSIT: Balance = 0;
 const ONE_YOCTO: Balance = 1;
 pub(crate) const TIME_IN_WEEK: u64 = 604800000; // 5 min // 604800000; // 1 week
+pub(crate) const TIME_IN_DAY: u64 = 86400000;
+pub(crate) const DEFAULT_QUOTE_MAX_AGE: u64 = 300000; // 5 min
+pub(crate) const DEFAULT_MAINTENANCE_HEALTH: u64 = 120;
+pub(crate) const DEFAULT_MAX_APPRAISAL_MULTIPLE: u64 = 5;
 
 #[ext_contract(ext_self)]
 pub trait ExtSelf {
-  fn loan_resolve_nft(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId, price: Balance, percent: u64);
+  fn loan_resolve_nft(&mut self, request: LoanRequest);
+  fn loan_resolve_nft_tier(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId);
   fn loan_resolve_nft_claim(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId);
+  fn loan_resolve_oracle_price(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId);
 
-  fn on_transfer_nft_pay(&mut self, account_id: AccountId, amount_sent: U128, fee: U128, recipient: AccountId, contract_token_id: TokenId, contract_id: AccountId, token_id: TokenId);
+  fn on_transfer_nft_pay(&mut self, rollback: PayRollback);
   fn on_transfer_resolve_nft(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, contract_token_id: TokenId, contract_id: AccountId, token_id: TokenId, expire_date: u64);
 }
 
@@ -68,88 +94,364 @@ pub struct LoanFactory {
     pub whitelist: HashMap<ContractId, bool>,
 
     pub owner_id: AccountId,
+
+    pub buyout_price_by_nft: LookupMap<TokenId, Balance>,
+    pub buyout_pending_by_nft: LookupMap<TokenId, AccountId>,
+    pub payout_by_account: LookupMap<AccountId, Balance>,
+    pub total_payout: U128,
+
+    pub notes: NonFungibleToken,
+    pub note_price_by_nft: LookupMap<TokenId, Balance>,
+
+    pub currency: Option<AccountId>,
+
+    pub pool_id: Option<PoolId>,
+
+    pub tranche_by_account: LookupMap<AccountId, Tranche>,
+    pub senior_balance: U128,
+    pub senior_shares: U128,
+    pub senior_epoch: u64,
+    pub junior_epoch: u64,
+    pub share_epoch_by_account: LookupMap<AccountId, u64>,
+    pub senior_max_apr: u64,
+    pub junior_fee_multiplier: u64,
+
+    pub withdraw_queue: TreeMap<u64, WithdrawRequest>,
+    pub withdraw_queue_by_account: LookupMap<AccountId, u64>,
+    pub withdraw_queue_next_id: u64,
+    pub withdraw_queue_total: U128,
+
+    pub locks_by_account: LookupMap<AccountId, Vec<DepositLock>>,
+    pub total_boost: U128,
+    pub senior_boost: U128,
+
+    pub unbonding_epochs: u64,
+    pub unbonding_by_account: LookupMap<AccountId, Unbonding>,
+    pub withdraw_cap: u64,
+    pub withdraw_window: u64,
+    pub withdraw_window_start: u64,
+    pub withdraw_window_amount: U128,
+
+    pub reserve_ratio: u64,
+    pub max_utilization: u64,
+
+    pub exposure_by_contract: LookupMap<ContractId, Balance>,
+    pub exposure_cap_by_contract: LookupMap<ContractId, ExposureCap>,
+
+    pub borrow_limit: BorrowLimit,
+    pub borrow_limit_by_account: LookupMap<AccountId, BorrowLimit>,
+
+    pub oracle_by_contract: LookupMap<ContractId, OracleConfig>,
+
+    pub price_feed_by_contract: LookupMap<ContractId, PriceFeed>,
+
+    pub price_keys: UnorderedMap<PublicKey, PriceKey>,
+    pub quote_max_age: u64,
+
+    pub price_history_by_contract: LookupMap<ContractId, PriceHistory>,
+    pub twap_window_by_contract: LookupMap<ContractId, u64>,
+
+    pub tiers_by_contract: LookupMap<ContractId, Vec<Tier>>,
+    pub tier_by_nft: LookupMap<TokenId, String>,
+
+    pub appraisal_by_nft: UnorderedMap<TokenId, Appraisal>,
+    pub price_managers: UnorderedSet<AccountId>,
+    pub max_appraisal_multiple: u64,
+
+    pub maintenance_health: u64,
+    pub liquidation_health: Option<u64>,
+
+    pub keeper_bounty: Balance,
+    pub keeper_reserve: U128,
+    pub keeper_cursor: Option<TokenId>,
+
+    pub loan_by_expiry: TreeMap<(u64, TokenId), ()>,
+
+    pub loan_nfts: UnorderedSet<TokenId>,
+
+    pub loan_id_by_nft: LookupMap<TokenId, u64>,
+    pub loan_records: LookupMap<u64, VersionedLoanRecord>,
+    pub loan_history_by_account: LookupMap<AccountId, Vector<u64>>,
+    pub loan_history_by_contract: LookupMap<ContractId, Vector<u64>>,
+
+    pub credit_config: CreditConfig,
+    pub credit_by_account: LookupMap<AccountId, CreditHistory>,
+
+    pub loan_duration: u64,
+    pub grace_period: u64,
+    pub penalty_rate: u64,
 }
 
 impl LoanFactory {
-    pub fn new<S, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11, S12, S13>(
+    /// Storage prefixes are derived from the pool id, see `pool_storage_key`
+    pub fn new(
         owner_id: AccountId,
         commission: u128,
-        account_prefix: S,
-        loan_by_nft_prefix: S2,
-        loan_by_account_prefix: S3,
-        nft_owner_prefix: S4,
-        price_by_nft_prefix: S5,
-        loan_date_by_nft_prefix: S6,
-        claim_date_by_nft_prefix: S7,
-        reward_by_account_prefix: S8,
-        percent_by_nft_prefix: S9,
-        nft_by_owner_prefix: S10,
-        percent_by_contract_prefix: S11,
-        price_by_contract_prefix: S12,
-      shares_by_account_prefix: S13
-    ) -> Self
-        where
-            S: IntoStorageKey,
-            S2: IntoStorageKey,
-            S3: IntoStorageKey,
-            S4: IntoStorageKey,
-            S5: IntoStorageKey,
-            S6: IntoStorageKey,
-            S7: IntoStorageKey,
-            S8: IntoStorageKey,
-            S9: IntoStorageKey,
-            S10: IntoStorageKey,
-            S11: IntoStorageKey,
-            S12: IntoStorageKey,
-            S13: IntoStorageKey,
-    {
+        pool_id: Option<PoolId>,
+    ) -> Self {
+        let key = |key: StorageKey| pool_storage_key(pool_id.as_ref(), key);
+
         let mut this = Self {
           total_shares: U128::from(0),
             commission,
             total_rewards_pool: U128::from(0),
             total_loan: U128::from(0),
             total_balance: U128::from(0),
-            accounts: TreeMap::new(account_prefix),
-            loan_by_nft: LookupMap::new(loan_by_nft_prefix),
-            loan_by_account: LookupMap::new(loan_by_account_prefix),
-            owner_by_nft: LookupMap::new(nft_owner_prefix),
-            price_by_nft: LookupMap::new(price_by_nft_prefix),
-            percent_by_nft: LookupMap::new(percent_by_nft_prefix),
-            loan_date_by_nft: TreeMap::new(loan_date_by_nft_prefix),
-            claim_date_by_account: LookupMap::new(claim_date_by_nft_prefix),
-            reward_by_account: LookupMap::new(reward_by_account_prefix),
-            nft_by_owner: LookupMap::new(nft_by_owner_prefix),
-            percent_by_contract: LookupMap::new(percent_by_contract_prefix),
-            price_by_contract: LookupMap::new(price_by_contract_prefix),
-            shares_by_account: LookupMap::new(shares_by_account_prefix),
+            accounts: TreeMap::new(key(StorageKey::BalanceByAccount)),
+            loan_by_nft: LookupMap::new(key(StorageKey::LoanBalanceByAccount)),
+            loan_by_account: LookupMap::new(key(StorageKey::LoanByNft)),
+            owner_by_nft: LookupMap::new(key(StorageKey::NftContractOwner)),
+            price_by_nft: LookupMap::new(key(StorageKey::PriceByNft)),
+            percent_by_nft: LookupMap::new(key(StorageKey::PercentByNft)),
+            loan_date_by_nft: TreeMap::new(key(StorageKey::LoanDateByNft)),
+            claim_date_by_account: LookupMap::new(key(StorageKey::ClaimDateByAccount)),
+            reward_by_account: LookupMap::new(key(StorageKey::RewardByAccount)),
+            nft_by_owner: LookupMap::new(key(StorageKey::NftByOwner)),
+            percent_by_contract: LookupMap::new(key(StorageKey::PercentByContract)),
+            price_by_contract: LookupMap::new(key(StorageKey::PriceByContract)),
+            shares_by_account: LookupMap::new(key(StorageKey::SharesByAccount)),
+            note_price_by_nft: LookupMap::new(key(StorageKey::NotePriceByNft)),
+            notes: NonFungibleToken::new(
+                key(StorageKey::Notes),
+                owner_id.clone(),
+                None::<Vec<u8>>,
+                None::<Vec<u8>>,
+                Some(key(StorageKey::NotesApproval)),
+            ),
             owner_id,
             whitelist: HashMap::new(),
+            buyout_price_by_nft: LookupMap::new(key(StorageKey::BuyoutPriceByNft)),
+            buyout_pending_by_nft: LookupMap::new(key(StorageKey::BuyoutPendingByNft)),
+            payout_by_account: LookupMap::new(key(StorageKey::PayoutByAccount)),
+            total_payout: U128(0),
+            currency: None,
+            pool_id: pool_id.clone(),
+            tranche_by_account: LookupMap::new(key(StorageKey::TrancheByAccount)),
+            senior_balance: U128::from(0),
+            senior_shares: U128::from(0),
+            senior_epoch: 0,
+            junior_epoch: 0,
+            share_epoch_by_account: LookupMap::new(key(StorageKey::ShareEpochByAccount)),
+            senior_max_apr: 8,
+            junior_fee_multiplier: 2,
+            withdraw_queue: TreeMap::new(key(StorageKey::WithdrawQueue)),
+            withdraw_queue_by_account: LookupMap::new(key(StorageKey::WithdrawQueueByAccount)),
+            withdraw_queue_next_id: 0,
+            withdraw_queue_total: U128::from(0),
+            locks_by_account: LookupMap::new(key(StorageKey::LocksByAccount)),
+            total_boost: U128::from(0),
+            senior_boost: U128::from(0),
+            unbonding_epochs: 0,
+            unbonding_by_account: LookupMap::new(key(StorageKey::UnbondingByAccount)),
+            withdraw_cap: 100,
+            withdraw_window: TIME_IN_DAY,
+            withdraw_window_start: 0,
+            withdraw_window_amount: U128::from(0),
+            reserve_ratio: 0,
+            max_utilization: 100,
+            exposure_by_contract: LookupMap::new(key(StorageKey::ExposureByContract)),
+            exposure_cap_by_contract: LookupMap::new(key(StorageKey::ExposureCapByContract)),
+            borrow_limit: BorrowLimit::default(),
+            borrow_limit_by_account: LookupMap::new(key(StorageKey::BorrowLimitByAccount)),
+            oracle_by_contract: LookupMap::new(key(StorageKey::OracleByContract)),
+            price_feed_by_contract: LookupMap::new(key(StorageKey::PriceFeedByContract)),
+            price_keys: UnorderedMap::new(key(StorageKey::PriceKeys)),
+            quote_max_age: DEFAULT_QUOTE_MAX_AGE,
+            price_history_by_contract: LookupMap::new(key(StorageKey::PriceHistoryByContract)),
+            twap_window_by_contract: LookupMap::new(key(StorageKey::TwapWindowByContract)),
+            tiers_by_contract: LookupMap::new(key(StorageKey::TiersByContract)),
+            tier_by_nft: LookupMap::new(key(StorageKey::TierByNft)),
+            appraisal_by_nft: UnorderedMap::new(key(StorageKey::AppraisalByNft)),
+            price_managers: UnorderedSet::new(key(StorageKey::PriceManagers)),
+            max_appraisal_multiple: DEFAULT_MAX_APPRAISAL_MULTIPLE,
+            maintenance_health: DEFAULT_MAINTENANCE_HEALTH,
+            liquidation_health: None,
+            keeper_bounty: 0,
+            keeper_reserve: U128(0),
+            keeper_cursor: None,
+            loan_by_expiry: TreeMap::new(key(StorageKey::LoanByExpiry)),
+            loan_nfts: UnorderedSet::new(key(StorageKey::LoanNfts)),
+            loan_id_by_nft: LookupMap::new(key(StorageKey::LoanIdByNft)),
+            loan_records: LookupMap::new(key(StorageKey::VersionedLoanRecords)),
+            loan_history_by_account: LookupMap::new(key(StorageKey::LoanHistoryByAccount)),
+            loan_history_by_contract: LookupMap::new(key(StorageKey::LoanHistoryByContract)),
+            credit_config: CreditConfig::default(),
+            credit_by_account: LookupMap::new(key(StorageKey::CreditByAccount)),
+            loan_duration: TIME_IN_WEEK,
+            grace_period: 0,
+            penalty_rate: 0,
         };
 
         this
     }
-}
 
-impl LoanFactoryCore for LoanFactory {
-    fn loan_nft(&mut self, token_id: TokenId, contract_id: ContractId) {
-      self.assert_nft_whitelist(&contract_id);
+    pub(crate) fn internal_nft_pay(&mut self, signer_id: &AccountId, token_id: TokenId, contract_id: ContractId, balance: Balance) {
+      let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
 
-      let signer_id = env::signer_account_id();
+      let loan_amount = self.internal_rest_of_loan(&contract_token_id).0;
+      let fee = self.internal_loan_fee(&contract_token_id, loan_amount);
+      let penalty = self.internal_loan_penalty(&contract_token_id, loan_amount);
+      let return_amount = loan_amount + fee;
+
+      self.assert_loan_not_defaulted(&contract_token_id);
+      self.assert_no_pending_buyout(&contract_token_id);
+
+      if return_amount != balance {
+        env::panic_str(&format!("Invalid pay amount, require {}, current {}", return_amount.to_string(), balance.to_string()));
+      }
+
+      let note_holder = self.internal_note_holder(&contract_token_id);
+
+      self.internal_decrease_loan_nft(&contract_token_id, &U128(loan_amount));
+      self.internal_decrease_loan_balance(signer_id, &U128(loan_amount));
+      self.internal_burn_note(&contract_token_id);
+      self.internal_close_loan_record(&contract_token_id, LoanOutcome::Repaid, return_amount);
+
+      if let Some(note_holder) = &note_holder {
+        self.internal_send_payout(note_holder, return_amount);
+      } else {
+        self.total_loan = U128::from(self.total_loan.0 - loan_amount);
+        self.total_rewards_pool = U128::from(self.total_rewards_pool.0 + fee - penalty);
+        // late penalties fund the keeper bounties
+        self.keeper_reserve = U128::from(self.keeper_reserve.0 + penalty);
+        self.internal_process_withdraw_queue();
+      }
+
+      if note_holder.is_none() && self.currency.is_none() {
+        Promise::new(env::current_account_id())
+          .transfer(return_amount)
+          .then(
+          ext_self::on_transfer_nft_pay(
+            PayRollback {
+              account_id: signer_id.clone(),
+              contract_id: contract_id.clone(),
+              token_id: token_id.clone(),
+              contract_token_id: contract_token_id.clone(),
+              principal: U128::from(loan_amount),
+              fee: U128::from(fee - penalty),
+              penalty: U128::from(penalty),
+            },
+            env::current_account_id(),
+            0,
+            CALLBACK_ON_PAY,
+          )
+        );
+      } else {
+        LoanNftPay {
+          owner_id: signer_id,
+          contract_id: &contract_id,
+          token_id: &token_id,
+          loan_amount: &U128::from(return_amount),
+        }.emit();
+      }
+
+      // self.total_balance = U128::from(self.total_balance.0 );
+
+      // LoanNftPay {
+      //     owner_id: &signer_id,
+      //     contract_id: &contract_id,
+      //     token_id: &token_id,
+      //     loan_amount: &U128::from(loan_amount),
+      //   }.emit();
+
+      self.loan_nft_claim(token_id.clone(), contract_id.clone());
+    }
+
+    /// Takes the collateral of a defaulted loan: to the note holder, or to the pool with the loss written off.
+    /// Returns the borrower.
+    pub(crate) fn internal_default_loan(&mut self, token_id: &TokenId, contract_id: &ContractId, outcome: LoanOutcome) -> AccountId {
+      let current_id = env::current_account_id();
+      let contract_token_id = self.internal_get_token_id(contract_id, token_id);
+      let owner_id = self.owner_by_nft.get(&contract_token_id).expect("Not found token owner");
+
+      self.assert_no_pending_buyout(&contract_token_id);
+
+      let note_holder = self.internal_note_holder(&contract_token_id);
+
+      self.internal_remove_nft_owner(&owner_id, &contract_token_id);
+
+      if let Some(note_holder) = note_holder {
+        // collateral goes to the note holder, who can take it with loan_nft_claim
+        let loan_amount = self.internal_rest_of_loan(&contract_token_id);
+
+        self.internal_decrease_loan_nft(&contract_token_id, &loan_amount);
+        self.internal_decrease_loan_balance(&owner_id, &loan_amount);
+        self.internal_set_nft_owner(&note_holder, &contract_token_id);
+      } else {
+        // default, the pool writes the loan off
+        let loan_amount = self.internal_rest_of_loan(&contract_token_id);
+
+        self.internal_decrease_loan_nft(&contract_token_id, &loan_amount);
+        self.internal_decrease_loan_balance(&owner_id, &loan_amount);
+        self.internal_allocate_loss(contract_id, token_id, loan_amount.0);
+        self.internal_set_nft_owner(&current_id, &contract_token_id);
+      }
+
+      self.internal_remove_loan_expire_date(&contract_token_id);
+      self.internal_remove_buyout(&contract_token_id);
+      self.internal_burn_note(&contract_token_id);
+      self.internal_close_loan_record(&contract_token_id, outcome, 0);
+
+      owner_id
+    }
+
+    /// Collection price: oracle when configured, otherwise the manual price or its twap
+    pub(crate) fn internal_loan_nft_by_collection(&mut self, signer_id: AccountId, token_id: TokenId, contract_id: ContractId) {
+      if let Some(oracle) = self.oracle_by_contract.get(&contract_id) {
+        ext_oracle::oracle_price(
+          contract_id.clone(),
+
+          oracle.oracle_id,
+          NO_DEPOSIT,
+          GAS_FOR_ORACLE_PRICE,
+        ).then(ext_self::loan_resolve_oracle_price(
+          signer_id,
+          contract_id,
+          token_id,
+
+          env::current_account_id(),
+          NO_DEPOSIT,
+          env::prepaid_gas() - GAS_FOR_ORACLE_PRICE - GAS_FOR_LOAN_ORACLE,
+        ));
+
+        return;
+      }
+
+      let price = self.internal_loan_price(&contract_id);
+      let percent = self.percent_by_contract.get(&contract_id).expect("Not found percent for current nft");
+
+      self.internal_lend_nft(signer_id, token_id, contract_id, price, percent, None);
+    }
+
+    /// Checks the pool limits and takes the nft, `price` and `percent` are already resolved from the tier, the oracle or the manual value
+    pub(crate) fn internal_lend_nft(&mut self, signer_id: AccountId, token_id: TokenId, contract_id: ContractId, price: Balance, percent: u64, tier: Option<String>) {
       let receiver_id = env::current_account_id();
       let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
 
       let loan = self.loan_by_nft.get(&contract_token_id).unwrap_or_else(|| 0);
 
-      let price = self.price_by_contract.get(&contract_id).expect("Not found price for current nft");
-      let percent = self.percent_by_contract.get(&contract_id).expect("Not found percent for current nft");
+      self.assert_credit(&signer_id);
+      let percent = self.internal_credit_percent(&signer_id, percent);
+      let loan_amount = price * ((100 - percent) as u128) / 100;
+
+      if loan_amount == 0 {
+        env::panic_str("Loan amount is zero");
+      }
 
       self.assert_available_balance(&U128::from(price));
+      self.assert_lendable_balance(loan_amount);
+      self.assert_nft_exposure(&contract_id, loan_amount);
+      self.assert_borrow_limit(&signer_id, loan_amount);
 
         if loan > 0 {
             env::panic_str("Nft already in loan");
         }
 
         self.internal_set_nft_owner(&signer_id, &contract_token_id);
+        // reserved until the transfer resolves, so parallel requests see it
+        self.internal_increase_exposure(&contract_token_id, loan_amount);
+        self.internal_increase_loan_balance(&signer_id, &U128::from(loan_amount));
+        self.total_loan = U128::from(self.total_loan.0 + loan_amount);
 
         ext_nft::nft_transfer(
             receiver_id,
@@ -161,66 +463,61 @@ impl LoanFactoryCore for LoanFactory {
             ONE_YOCTO,
             GAS_FOR_NFT_TRANSFER,
         ).then(ext_self::loan_resolve_nft(
-            signer_id.clone(),
-            contract_id.clone(),
-            token_id.clone(),
-            contract_token_id.clone(),
-            price.clone(),
-            percent.clone(),
+            LoanRequest {
+              receiver_id: signer_id.clone(),
+              contract_id: contract_id.clone(),
+              token_id: token_id.clone(),
+              contract_token_id: contract_token_id.clone(),
+              price: U128::from(price),
+              percent,
+              tier,
+            },
 
             env::current_account_id().clone(),
             NO_DEPOSIT,
             env::prepaid_gas() - GAS_FOR_LOAN_NFT,
         ));
     }
+}
 
-    fn loan_nft_pay(&mut self, token_id: TokenId, contract_id: ContractId) {
-      let signer_id = env::predecessor_account_id();
-      let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
-
-      let loan_amount = self.internal_rest_of_loan(&contract_token_id).0;
-      let balance = env::attached_deposit();
-      let fee = loan_amount * self.commission / 100;
-      let return_amount = loan_amount + fee;
+impl LoanFactoryCore for LoanFactory {
+    fn loan_nft(&mut self, token_id: TokenId, contract_id: ContractId) {
+      self.assert_nft_whitelist(&contract_id);
 
-      self.assert_loan_not_expired(&contract_token_id);
+      let signer_id = env::signer_account_id();
 
-      if return_amount != balance {
-        env::panic_str(&format!("Invalid attached deposit, require {}, current {}", return_amount.to_string(), balance.to_string()));
+      if let Some((price, percent)) = self.internal_appraisal_of(&contract_id, &token_id) {
+        self.internal_lend_nft(signer_id, token_id, contract_id, price, percent, None);
+        return;
       }
 
-      self.internal_decrease_loan_nft(&contract_token_id, &U128(loan_amount));
-      self.internal_decrease_loan_balance(&signer_id, &U128(loan_amount));
-      self.total_loan = U128::from(self.total_loan.0 - loan_amount);
-      self.total_rewards_pool = U128::from(self.total_rewards_pool.0 + fee);
-
-      Promise::new(env::current_account_id())
-        .transfer(return_amount)
-        .then(
-        ext_self::on_transfer_nft_pay(
-          env::predecessor_account_id(),
-          U128::from(return_amount),
-          U128::from(fee),
-          env::current_account_id(),
-          contract_token_id.clone(),
-          contract_id.clone(),
-          token_id.clone(),
-          env::current_account_id(),
-          0,
-          CALLBACK_ON_PAY,
-        )
-      );
+      match self.internal_tier_by_token_id(&contract_id, &token_id) {
+        TierMatch::Tier(tier) => self.internal_lend_nft(signer_id, token_id, contract_id, tier.price.0, tier.percent, Some(tier.name)),
+        TierMatch::Metadata => {
+          ext_nft_token::nft_token(
+            token_id.clone(),
 
-      // self.total_balance = U128::from(self.total_balance.0 );
+            contract_id.clone(),
+            NO_DEPOSIT,
+            GAS_FOR_NFT_TOKEN,
+          ).then(ext_self::loan_resolve_nft_tier(
+            signer_id,
+            contract_id,
+            token_id,
 
-      // LoanNftPay {
-      //     owner_id: &signer_id,
-      //     contract_id: &contract_id,
-      //     token_id: &token_id,
-      //     loan_amount: &U128::from(loan_amount),
-      //   }.emit();
+            env::current_account_id(),
+            NO_DEPOSIT,
+            env::prepaid_gas() - GAS_FOR_NFT_TOKEN - GAS_FOR_LOAN_TIER,
+          ));
+        }
+        TierMatch::Collection => self.internal_loan_nft_by_collection(signer_id, token_id, contract_id),
+      }
+    }
 
-      self.loan_nft_claim(token_id.clone(), contract_id.clone());
+    fn loan_nft_pay(&mut self, token_id: TokenId, contract_id: ContractId) {
+      self.assert_native_currency();
+
+      self.internal_nft_pay(&env::predecessor_account_id(), token_id, contract_id, env::attached_deposit())
     }
 
     fn loan_nft_claim(&mut self, token_id: TokenId, contract_id: ContractId) {
@@ -231,6 +528,7 @@ impl LoanFactoryCore for LoanFactory {
         if loan > 0 {
             env::panic_str(&"Close loan first");
         }
+        self.assert_no_pending_buyout(&contract_token_id);
 
         ext_nft::nft_transfer(
             receiver_id.clone(),
@@ -278,15 +576,11 @@ impl LoanFactoryCore for LoanFactory {
   fn loan_nft_claim_expired(&mut self, token_id: TokenId, contract_id: ContractId) {
     // self.assert_owner();
 
-    let current_id = env::current_account_id();
     let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
-    let owner_id = self.owner_by_nft.get(&contract_token_id).expect("Not found token owner");
 
-    self.assert_loan_expired(&contract_token_id);
+    self.assert_loan_defaulted(&contract_token_id);
 
-    self.internal_remove_nft_owner(&owner_id, &contract_token_id);
-    self.internal_set_nft_owner(&current_id, &contract_token_id);
-    self.loan_date_by_nft.remove(&contract_token_id);
+    let owner_id = self.internal_default_loan(&token_id, &contract_id, LoanOutcome::Defaulted);
 
     LoanNftClaimExpired {
         old_owner_id: &owner_id,
@@ -356,23 +650,42 @@ impl LoanFactoryCore for LoanFactory {
 }
 
 impl LoanFactoryResolver for LoanFactory {
-    fn loan_resolve_nft(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId, price: Balance, percent: u64) {
+    fn loan_resolve_nft(&mut self, loan_id: u64, request: LoanRequest) {
         let is_success = is_promise_success();
+        let LoanRequest { receiver_id, contract_id, token_id, contract_token_id, price, percent, tier } = request;
+        let price = price.0;
+        let loan_amount = U128::from(price  * ((100 - percent) as u128) / (100 as u128));
+
+        // the reserved exposure is booked with the loan below, `total_loan` keeps the principal reserved at the request
+        self.internal_decrease_exposure(&contract_token_id, loan_amount.0);
 
         if is_success {
-          let loan_amount = U128::from(price  * ((100 - percent) as u128) / (100 as u128));
-          let expire_date = date_now() + TIME_IN_WEEK;
+          let expire_date = date_now() + self.loan_duration;
 
-          self.total_loan = U128::from(self.total_loan.0 + loan_amount.0);
           self.internal_increase_loan_nft(&contract_token_id, &loan_amount);
-          self.internal_increase_loan_balance(&receiver_id, &loan_amount);
           self.internal_set_loan_expire_date(&contract_token_id, &expire_date);
           // self.owner_by_nft.insert(&contract_token_id, &receiver_id);
           self.price_by_nft.insert(&contract_token_id, &price);
           self.percent_by_nft.insert(&contract_token_id, &percent);
-
-          Promise::new(receiver_id.clone())
-            .transfer(loan_amount.0)
+          if let Some(tier) = &tier {
+            self.tier_by_nft.insert(&contract_token_id, tier);
+          }
+          self.internal_mint_note(&contract_token_id);
+          self.internal_open_loan_record(loan_id, LoanRecord {
+            borrower_id: receiver_id.clone(),
+            contract_id: contract_id.clone(),
+            token_id: token_id.clone(),
+            price,
+            percent,
+            terms: self.internal_new_loan_terms(loan_amount.0, self.internal_credit_commission(&receiver_id)),
+            started_at: date_now(),
+            expire_date,
+            repaid: 0,
+            closed_at: None,
+            outcome: LoanOutcome::Open,
+          });
+
+          self.internal_send(&receiver_id, loan_amount.0)
             .then(
               ext_self::on_transfer_resolve_nft(
                 env::current_account_id(),
@@ -396,6 +709,8 @@ impl LoanFactoryResolver for LoanFactory {
           //     price: &loan_amount,
           //   }.emit();
         } else {
+          self.total_loan = U128::from(self.total_loan.0 - loan_amount.0);
+          self.internal_decrease_loan_balance(&receiver_id, &loan_amount);
           self.internal_remove_nft_owner(&receiver_id, &contract_token_id);
         }
     }
@@ -405,9 +720,12 @@ impl LoanFactoryResolver for LoanFactory {
 
         if is_success {
           self.internal_remove_nft_owner(&receiver_id, &contract_token_id);
-          self.loan_date_by_nft.remove(&contract_token_id);
+          self.internal_remove_loan_expire_date(&contract_token_id);
           self.price_by_nft.remove(&contract_token_id);
           self.percent_by_nft.remove(&contract_token_id);
+          self.tier_by_nft.remove(&contract_token_id);
+          self.internal_remove_buyout(&contract_token_id);
+          self.loan_id_by_nft.remove(&contract_token_id);
 
           LoanNftClaim {
             receiver_id: &receiver_id,
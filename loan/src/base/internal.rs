@@ -5,25 +5,18 @@ use near_sdk::json_types::U128;
 use crate::base::{ContractId, TokenId, LoanFactory};
 use crate::utils::date_now;
 use crate::meta::JsonLoan;
-use crate::base::base_impl::TIME_IN_WEEK;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 
 #[derive(BorshStorageKey, BorshSerialize)]
 pub enum StorageKey {
   TokensPerOwner { account_hash: Vec<u8> },
+  PoolTokensPerOwner { pool_hash: Vec<u8>, account_hash: Vec<u8> },
 }
 
 impl LoanFactory {
     pub(crate) fn assert_owner(&self) {
         assert_eq!(env::predecessor_account_id(), self.owner_id, "Unauthorized");
     }
-    pub(crate) fn assert_loan_expired(&self, contract_token_id: &TokenId) {
-        let expire_date = self.loan_date_by_nft.get(&contract_token_id).expect("Not found loan expire date");
-
-        if expire_date >= date_now() {
-            env::panic_str("Loan does not expired");
-        }
-    }
     pub(crate) fn assert_loan_not_expired(&self, contract_token_id: &TokenId) {
         let expire_date = self.loan_date_by_nft.get(&contract_token_id).expect("Not found loan expire date");
 
@@ -67,6 +60,10 @@ impl LoanFactory {
         let next = current + amount.0;
 
         self.loan_by_nft.insert(&contract_token_id, &next);
+        // a sold note takes the loan off the pool exposure
+        if self.internal_note_holder(contract_token_id).is_none() {
+            self.internal_increase_exposure(contract_token_id, amount.0);
+        }
     }
     pub(crate) fn internal_decrease_loan_nft(&mut self, contract_token_id: &TokenId, amount: &U128) {
         let current = self.loan_by_nft.get(&contract_token_id).unwrap_or_else(|| 0);
@@ -78,6 +75,9 @@ impl LoanFactory {
         let next = current - amount.0;
 
         self.loan_by_nft.insert(&contract_token_id, &next);
+        if self.internal_note_holder(contract_token_id).is_none() {
+            self.internal_decrease_exposure(contract_token_id, amount.0);
+        }
     }
 
     pub(crate) fn internal_rest_of_loan(&self, contract_token_id: &TokenId) -> U128 {
@@ -85,11 +85,20 @@ impl LoanFactory {
     }
 
     pub(crate) fn internal_set_nft_price(&mut self, contract_id: &ContractId, price: &Balance, percent: &u64) {
-        self.price_by_contract.insert(&contract_id, &price);
+        self.internal_update_price(contract_id, *price);
         self.percent_by_contract.insert(&contract_id, &percent);
     }
+    /// Keeps `loan_by_expiry` in sync with the expire date
     pub(crate) fn internal_set_loan_expire_date(&mut self, contract_token_id: &TokenId, date: &u64) {
-        self.loan_date_by_nft.insert(&contract_token_id, &date);
+        if let Some(old_date) = self.loan_date_by_nft.insert(contract_token_id, date) {
+          self.loan_by_expiry.remove(&(old_date, contract_token_id.clone()));
+        }
+        self.loan_by_expiry.insert(&(*date, contract_token_id.clone()), &());
+    }
+    pub(crate) fn internal_remove_loan_expire_date(&mut self, contract_token_id: &TokenId) {
+        if let Some(date) = self.loan_date_by_nft.remove(contract_token_id) {
+          self.loan_by_expiry.remove(&(date, contract_token_id.clone()));
+        }
     }
 
     pub(crate) fn enum_get_loan(&self, contract_token_id: &TokenId) -> JsonLoan {
@@ -101,7 +110,7 @@ impl LoanFactory {
       let started_at = if expire_date == 0 {
         0
       } else {
-        expire_date - TIME_IN_WEEK
+        expire_date - self.internal_loan_terms(contract_token_id).duration
       };
 
       JsonLoan {
@@ -112,16 +121,27 @@ impl LoanFactory {
         started_at,
         expired_at: expire_date,
         expired,
+        note_owner_id: self.notes.owner_by_id.get(contract_token_id),
+        pool_id: self.pool_id.clone(),
+        tier: self.tier_by_nft.get(contract_token_id),
+        loan_id: self.loan_id_by_nft.get(contract_token_id),
       }
     }
 
     pub(crate) fn internal_set_nft_owner(&mut self, account_id: &AccountId, contract_token_id: &TokenId) {
       self.owner_by_nft.insert(&contract_token_id, &account_id);
+      self.loan_nfts.insert(contract_token_id);
 
       let mut receiver_tokens = self.nft_by_owner.get(&account_id).unwrap_or_else(|| {
-        UnorderedSet::new(StorageKey::TokensPerOwner {
-          account_hash: env::sha256(account_id.as_bytes()),
-        })
+        let account_hash = env::sha256(account_id.as_bytes());
+
+        match &self.pool_id {
+          Some(pool_id) => UnorderedSet::new(StorageKey::PoolTokensPerOwner {
+            pool_hash: env::sha256(pool_id.as_bytes()),
+            account_hash,
+          }),
+          None => UnorderedSet::new(StorageKey::TokensPerOwner { account_hash }),
+        }
       });
       receiver_tokens.insert(&contract_token_id);
       self.nft_by_owner.insert(&account_id, &receiver_tokens);
@@ -129,6 +149,7 @@ impl LoanFactory {
 
     pub(crate) fn internal_remove_nft_owner(&mut self, account_id: &AccountId, contract_token_id: &TokenId) {
       self.owner_by_nft.remove(&contract_token_id);
+      self.loan_nfts.remove(contract_token_id);
 
       let mut owner_tokens = self.nft_by_owner.get(&account_id).unwrap_or_else(|| {
         env::panic_str("Unable to access tokens per owner in unguarded call.")
@@ -3,7 +3,7 @@ mod base;
 mod internal;
 
 pub use base_impl::{LoanFactory};
-pub use base::{ContractId, TokenId};
+pub use base::{ContractId, LoanRequest, PayRollback, PoolId, TokenId};
 
 pub use self::base::{LoanFactoryCore, LoanFactoryResolver};
 
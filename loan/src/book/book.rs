@@ -0,0 +1,30 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::base::ContractId;
+use crate::meta::JsonLoan;
+
+/// Repaid loans wait for `loan_nft_claim`, liquidated nfts are kept by the pool or the note holder
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum LoanStatus {
+  Active,
+  Expired,
+  Repaid,
+  Liquidated,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LoanFilter {
+  pub contract_id: Option<ContractId>,
+  pub owner_id: Option<AccountId>,
+  pub status: Option<LoanStatus>,
+  pub min_principal: Option<U128>,
+  pub max_principal: Option<U128>,
+}
+
+pub trait LoanFactoryBook {
+  fn loan_nfts(&self, from_index: Option<U128>, limit: Option<u64>, filter: Option<LoanFilter>) -> Vec<JsonLoan>;
+}
@@ -0,0 +1,30 @@
+use crate::base::{LoanFactory, TokenId};
+use crate::book::{LoanFactoryBook, LoanFilter};
+use crate::meta::JsonLoan;
+use near_sdk::json_types::U128;
+use near_sdk::require;
+
+impl LoanFactoryBook for LoanFactory {
+  /// `from_index` counts the loans matching `filter`
+  fn loan_nfts(&self, from_index: Option<U128>, limit: Option<u64>, filter: Option<LoanFilter>) -> Vec<JsonLoan> {
+    let filter = filter.unwrap_or_default();
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+
+    let loans: Box<dyn Iterator<Item = TokenId>> = match &filter.owner_id {
+      Some(owner_id) => match self.nft_by_owner.get(owner_id) {
+        Some(token_set) => Box::new(token_set.to_vec().into_iter()),
+        None => return vec![],
+      },
+      None => Box::new(self.loan_nfts.iter()),
+    };
+
+    loans
+      .filter(|contract_token_id| self.internal_loan_matches(contract_token_id, &filter))
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|contract_token_id| self.enum_get_loan(&contract_token_id))
+      .collect()
+  }
+}
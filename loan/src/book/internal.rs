@@ -0,0 +1,31 @@
+use crate::base::{LoanFactory, TokenId};
+use crate::book::{LoanFilter, LoanStatus};
+use crate::utils::date_now;
+
+impl LoanFactory {
+  pub(crate) fn internal_loan_status(&self, contract_token_id: &TokenId) -> LoanStatus {
+    match self.loan_date_by_nft.get(contract_token_id) {
+      // the date is removed when the collateral is taken
+      None => LoanStatus::Liquidated,
+      Some(_) if self.internal_rest_of_loan(contract_token_id).0 == 0 => LoanStatus::Repaid,
+      Some(expire_date) if expire_date < date_now() => LoanStatus::Expired,
+      Some(_) => LoanStatus::Active,
+    }
+  }
+
+  pub(crate) fn internal_loan_matches(&self, contract_token_id: &TokenId, filter: &LoanFilter) -> bool {
+    if let Some(contract_id) = &filter.contract_id {
+      if !contract_token_id.starts_with(&format!("{}||", contract_id)) {
+        return false;
+      }
+    }
+    if filter.status.is_some_and(|status| status != self.internal_loan_status(contract_token_id)) {
+      return false;
+    }
+
+    let principal = self.internal_rest_of_loan(contract_token_id).0;
+
+    filter.min_principal.is_none_or(|min_principal| principal >= min_principal.0)
+      && filter.max_principal.is_none_or(|max_principal| principal <= max_principal.0)
+  }
+}
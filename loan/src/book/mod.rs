@@ -0,0 +1,5 @@
+mod book_impl;
+mod book;
+mod internal;
+
+pub use self::book::{LoanFactoryBook, LoanFilter, LoanStatus};
@@ -0,0 +1,30 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::base::{ContractId, TokenId};
+
+/// Buyout taken by `internal_nft_buyout`, settled by the resolver once the nft reaches the buyer
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BuyoutSettlement {
+  pub buyer_id: AccountId,
+  pub owner_id: AccountId,
+  pub contract_id: ContractId,
+  pub token_id: TokenId,
+  pub contract_token_id: TokenId,
+  pub price: U128,
+  pub loan_amount: U128,
+  pub fee: U128,
+}
+
+pub trait LoanFactoryBuyout {
+  fn loan_nft_buyout_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128);
+  fn loan_nft_buyout_cancel(&mut self, token_id: TokenId, contract_id: ContractId);
+  fn loan_nft_buyout(&mut self, token_id: TokenId, contract_id: ContractId);
+
+  fn loan_nft_buyout_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128>;
+}
+
+pub trait LoanFactoryBuyoutResolver {
+  fn loan_resolve_nft_buyout(&mut self, settlement: BuyoutSettlement);
+}
@@ -0,0 +1,187 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, is_promise_success, AccountId, Balance, Gas};
+use crate::base::{ContractId, LoanFactory, TokenId};
+use crate::base::base_impl::ext_nft;
+use crate::buyout::{BuyoutSettlement, LoanFactoryBuyout, LoanFactoryBuyoutResolver};
+use crate::archive::LoanOutcome;
+use crate::event::{LoanNftBuyout, LoanNftBuyoutCancel, LoanNftBuyoutList};
+
+const GAS_FOR_NFT_TRANSFER: Gas = Gas(18_000_000_000_000);
+const GAS_FOR_LOAN_BUYOUT_NFT: Gas = Gas(60_000_000_000_000);
+const NO_DEPOSIT: Balance = 0;
+const ONE_YOCTO: Balance = 1;
+
+#[ext_contract(ext_self)]
+pub trait ExtSelf {
+  fn loan_resolve_nft_buyout(&mut self, settlement: BuyoutSettlement);
+}
+
+impl LoanFactoryBuyout for LoanFactory {
+  fn loan_nft_buyout_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128) {
+    let account_id = env::predecessor_account_id();
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.assert_nft_owner(&account_id, &contract_token_id);
+    self.assert_loan_not_expired(&contract_token_id);
+    self.assert_no_pending_buyout(&contract_token_id);
+
+    let min_price = self.internal_min_buyout_price(&contract_token_id);
+
+    if min_price == 0 {
+      env::panic_str("Loan already closed");
+    }
+    if price.0 < min_price {
+      env::panic_str(&format!("Buyout price must cover the loan, require at least {}", min_price));
+    }
+
+    self.buyout_price_by_nft.insert(&contract_token_id, &price.0);
+
+    LoanNftBuyoutList {
+      owner_id: &account_id,
+      contract_id: &contract_id,
+      token_id: &token_id,
+      price: &price,
+    }.emit();
+  }
+
+  fn loan_nft_buyout_cancel(&mut self, token_id: TokenId, contract_id: ContractId) {
+    let account_id = env::predecessor_account_id();
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.assert_nft_owner(&account_id, &contract_token_id);
+    self.assert_no_pending_buyout(&contract_token_id);
+
+    if self.buyout_price_by_nft.get(&contract_token_id).is_none() {
+      env::panic_str("Nft not listed for buyout");
+    }
+
+    self.internal_remove_buyout(&contract_token_id);
+
+    LoanNftBuyoutCancel {
+      owner_id: &account_id,
+      contract_id: &contract_id,
+      token_id: &token_id,
+    }.emit();
+  }
+
+  fn loan_nft_buyout(&mut self, token_id: TokenId, contract_id: ContractId) {
+    self.assert_native_currency();
+
+    self.internal_nft_buyout(&env::predecessor_account_id(), token_id, contract_id, env::attached_deposit())
+  }
+
+  fn loan_nft_buyout_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128> {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.buyout_price_by_nft.get(&contract_token_id).map(U128::from)
+  }
+}
+
+impl LoanFactoryBuyoutResolver for LoanFactory {
+  fn loan_resolve_nft_buyout(&mut self, settlement: BuyoutSettlement) {
+    let is_success = is_promise_success();
+    let BuyoutSettlement { buyer_id, owner_id, contract_id, token_id, contract_token_id, price, loan_amount, fee } = settlement;
+
+    let note_holder = self.internal_note_holder(&contract_token_id);
+
+    self.buyout_pending_by_nft.remove(&contract_token_id);
+
+    if is_success {
+      self.internal_decrease_loan_nft(&contract_token_id, &loan_amount);
+      self.internal_decrease_loan_balance(&owner_id, &loan_amount);
+
+      if note_holder.is_none() {
+        self.total_loan = U128::from(self.total_loan.0 - loan_amount.0);
+        self.total_rewards_pool = U128::from(self.total_rewards_pool.0 + fee.0);
+      }
+
+      self.internal_remove_nft_owner(&owner_id, &contract_token_id);
+      self.internal_remove_loan_expire_date(&contract_token_id);
+      self.price_by_nft.remove(&contract_token_id);
+      self.percent_by_nft.remove(&contract_token_id);
+      self.tier_by_nft.remove(&contract_token_id);
+      self.internal_burn_note(&contract_token_id);
+      self.internal_close_loan_record(&contract_token_id, LoanOutcome::BoughtOut, loan_amount.0 + fee.0);
+
+      if let Some(note_holder) = note_holder {
+        self.internal_send_payout(&note_holder, loan_amount.0 + fee.0);
+      } else {
+        self.internal_process_withdraw_queue();
+      }
+
+      let rest = price.0 - loan_amount.0 - fee.0;
+
+      if rest > 0 {
+        self.internal_send_payout(&owner_id, rest);
+      }
+
+      LoanNftBuyout {
+        owner_id: &owner_id,
+        buyer_id: &buyer_id,
+        contract_id: &contract_id,
+        token_id: &token_id,
+        price: &price,
+        loan_amount: &loan_amount,
+      }.emit();
+    } else {
+      env::log_str(&format!("Nft transfer to @{} failed. {} returned", buyer_id, price.0));
+
+      self.internal_send_payout(&buyer_id, price.0);
+    }
+  }
+}
+
+impl LoanFactory {
+  pub(crate) fn internal_nft_buyout(&mut self, buyer_id: &AccountId, token_id: TokenId, contract_id: ContractId, balance: Balance) {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+    let owner_id = self.owner_by_nft.get(&contract_token_id).expect("Not found token owner");
+    let price = self.buyout_price_by_nft.get(&contract_token_id).expect("Nft not listed for buyout");
+
+    self.assert_loan_not_expired(&contract_token_id);
+    self.assert_no_pending_buyout(&contract_token_id);
+
+    if buyer_id == &owner_id {
+      env::panic_str("Borrower can not buyout own nft, use loan_nft_pay");
+    }
+    if price != balance {
+      env::panic_str(&format!("Invalid buyout amount, require {}, current {}", price, balance));
+    }
+
+    let loan_amount = self.internal_rest_of_loan(&contract_token_id).0;
+    let fee = self.internal_loan_fee(&contract_token_id, loan_amount);
+
+    if loan_amount + fee > price {
+      env::panic_str("Buyout price does not cover the loan");
+    }
+
+    // the loan is closed only when the nft reaches the buyer
+    self.internal_remove_buyout(&contract_token_id);
+    self.buyout_pending_by_nft.insert(&contract_token_id, buyer_id);
+
+    ext_nft::nft_transfer(
+      buyer_id.clone(),
+      token_id.clone(),
+      None,
+      None,
+
+      contract_id.clone(),
+      ONE_YOCTO,
+      GAS_FOR_NFT_TRANSFER,
+    ).then(ext_self::loan_resolve_nft_buyout(
+      BuyoutSettlement {
+        buyer_id: buyer_id.clone(),
+        owner_id,
+        contract_id,
+        token_id,
+        contract_token_id,
+        price: U128::from(price),
+        loan_amount: U128::from(loan_amount),
+        fee: U128::from(fee),
+      },
+
+      env::current_account_id(),
+      NO_DEPOSIT,
+      env::prepaid_gas() - GAS_FOR_LOAN_BUYOUT_NFT,
+    ));
+  }
+}
@@ -0,0 +1,29 @@
+use crate::base::{LoanFactory, TokenId};
+use near_sdk::{AccountId, Balance, env};
+
+impl LoanFactory {
+  pub(crate) fn assert_nft_owner(&self, account_id: &AccountId, contract_token_id: &TokenId) {
+    let owner_id = self.owner_by_nft.get(contract_token_id).expect("Not found token owner");
+
+    if &owner_id != account_id {
+      env::panic_str("Only borrower can manage buyout");
+    }
+  }
+
+  pub(crate) fn internal_min_buyout_price(&self, contract_token_id: &TokenId) -> Balance {
+    let loan_amount = self.internal_rest_of_loan(contract_token_id).0;
+
+    loan_amount + self.internal_loan_fee(contract_token_id, loan_amount)
+  }
+
+  /// The loan is settled in the buyout callback, until then nothing else can touch it
+  pub(crate) fn assert_no_pending_buyout(&self, contract_token_id: &TokenId) {
+    if self.buyout_pending_by_nft.contains_key(contract_token_id) {
+      env::panic_str("Nft buyout is in progress");
+    }
+  }
+
+  pub(crate) fn internal_remove_buyout(&mut self, contract_token_id: &TokenId) {
+    self.buyout_price_by_nft.remove(contract_token_id);
+  }
+}
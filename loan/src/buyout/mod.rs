@@ -0,0 +1,5 @@
+mod buyout_impl;
+mod buyout;
+mod internal;
+
+pub use self::buyout::{BuyoutSettlement, LoanFactoryBuyout, LoanFactoryBuyoutResolver};
@@ -0,0 +1,19 @@
+use near_sdk::{AccountId, Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::meta::{JsonUnbonding, JsonWithdrawLimits};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Unbonding {
+  pub amount: Balance,
+  pub epoch_height: EpochHeight,
+}
+
+pub trait LoanFactoryCooldown {
+  fn loan_set_withdraw_limits(&mut self, unbonding_epochs: u64, withdraw_cap: u64, withdraw_window: u64);
+  fn loan_withdraw_finalize(&mut self) -> U128;
+
+  fn loan_withdraw_limits(&self) -> JsonWithdrawLimits;
+  fn loan_withdraw_limit_left(&self) -> U128;
+  fn loan_unbonding_of(&self, account_id: AccountId) -> Option<JsonUnbonding>;
+}
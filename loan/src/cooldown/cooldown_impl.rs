@@ -0,0 +1,56 @@
+use crate::base::LoanFactory;
+use crate::cooldown::LoanFactoryCooldown;
+use crate::meta::{JsonUnbonding, JsonWithdrawLimits};
+use crate::event::LoanWithdrawLimitsUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId};
+
+impl LoanFactoryCooldown for LoanFactory {
+  fn loan_set_withdraw_limits(&mut self, unbonding_epochs: u64, withdraw_cap: u64, withdraw_window: u64) {
+    self.assert_owner();
+
+    if !(1..=100).contains(&withdraw_cap) {
+      env::panic_str("Withdraw cap should be from 1 to 100 percent");
+    }
+    if withdraw_window == 0 {
+      env::panic_str("Invalid withdraw window");
+    }
+
+    self.unbonding_epochs = unbonding_epochs;
+    self.withdraw_cap = withdraw_cap;
+    self.withdraw_window = withdraw_window;
+
+    LoanWithdrawLimitsUpdate {
+      unbonding_epochs: &unbonding_epochs,
+      withdraw_cap: &withdraw_cap,
+      withdraw_window: &withdraw_window,
+    }.emit();
+  }
+
+  fn loan_withdraw_finalize(&mut self) -> U128 {
+    let account_id = env::predecessor_account_id();
+    let amount = self.internal_take_unbonded(&account_id, None);
+
+    self.internal_withdraw(&account_id, U128::from(amount))
+  }
+
+  fn loan_withdraw_limits(&self) -> JsonWithdrawLimits {
+    JsonWithdrawLimits {
+      unbonding_epochs: self.unbonding_epochs,
+      withdraw_cap: self.withdraw_cap,
+      withdraw_window: self.withdraw_window,
+    }
+  }
+
+  fn loan_withdraw_limit_left(&self) -> U128 {
+    U128::from(self.internal_withdraw_limit_left())
+  }
+
+  fn loan_unbonding_of(&self, account_id: AccountId) -> Option<JsonUnbonding> {
+    self.unbonding_by_account.get(&account_id).map(|unbonding| JsonUnbonding {
+      amount: U128::from(unbonding.amount),
+      epoch_height: unbonding.epoch_height,
+      unlock_epoch_height: unbonding.epoch_height + self.unbonding_epochs,
+    })
+  }
+}
@@ -0,0 +1,113 @@
+use crate::base::LoanFactory;
+use crate::cooldown::Unbonding;
+use crate::event::LoanWithdrawUnbond;
+use crate::utils::date_now;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+
+impl LoanFactory {
+  /// Starts an unbonding request, a new request adds the amount and restarts the period
+  pub(crate) fn internal_unbond(&mut self, account_id: &AccountId, amount: U128) {
+    let current = self.unbonding_by_account.get(account_id).map(|el| el.amount).unwrap_or(0);
+    let next = current + amount.0;
+
+    if amount.0 == 0 {
+      env::panic_str("The amount should be a positive number");
+    }
+    if next > self.internal_balance_of(account_id).0 {
+      env::panic_str("No funds");
+    }
+
+    self.unbonding_by_account.insert(account_id, &Unbonding {
+      amount: next,
+      epoch_height: env::epoch_height(),
+    });
+
+    LoanWithdrawUnbond {
+      account_id,
+      amount: &U128::from(next),
+      unlock_epoch_height: &(env::epoch_height() + self.unbonding_epochs),
+    }.emit();
+  }
+
+  /// Takes `amount` (or everything) from a finished unbonding request.
+  /// Without unbonding period the amount is returned as is.
+  pub(crate) fn internal_take_unbonded(&mut self, account_id: &AccountId, amount: Option<Balance>) -> Balance {
+    if let (0, Some(amount)) = (self.unbonding_epochs, amount) {
+      return amount;
+    }
+
+    let mut unbonding = self.unbonding_by_account.get(account_id).expect("Not found unbonding request, use loan_withdraw");
+
+    if env::epoch_height() < unbonding.epoch_height + self.unbonding_epochs {
+      env::panic_str(&format!("Unbonding until epoch {}", unbonding.epoch_height + self.unbonding_epochs));
+    }
+
+    let amount = amount.unwrap_or(unbonding.amount);
+
+    if amount > unbonding.amount {
+      env::panic_str("Amount is bigger than unbonded");
+    }
+
+    unbonding.amount -= amount;
+
+    if unbonding.amount == 0 {
+      self.unbonding_by_account.remove(account_id);
+    } else {
+      self.unbonding_by_account.insert(account_id, &unbonding);
+    }
+
+    // balance could go down after a loss
+    std::cmp::min(amount, self.internal_balance_of(account_id).0)
+  }
+
+  /// Returns `amount` to the unbonding request without restarting its period.
+  /// A newer request keeps its own start, the amount waits with it.
+  pub(crate) fn internal_restore_unbonded(&mut self, account_id: &AccountId, amount: Balance, epoch_height: u64) {
+    if self.unbonding_epochs == 0 || amount == 0 {
+      return;
+    }
+
+    let unbonding = match self.unbonding_by_account.get(account_id) {
+      Some(el) => Unbonding {
+        amount: el.amount + amount,
+        epoch_height: std::cmp::max(el.epoch_height, epoch_height),
+      },
+      None => Unbonding {
+        amount,
+        epoch_height,
+      },
+    };
+
+    self.unbonding_by_account.insert(account_id, &unbonding);
+  }
+
+  fn internal_withdraw_window_amount(&self) -> Balance {
+    if date_now() >= self.withdraw_window_start + self.withdraw_window {
+      0
+    } else {
+      self.withdraw_window_amount.0
+    }
+  }
+
+  /// Left to withdraw in the current window, the cap is a part of the balance at the window start
+  pub(crate) fn internal_withdraw_limit_left(&self) -> Balance {
+    let withdrawn = self.internal_withdraw_window_amount();
+    let cap = (self.total_balance.0 + withdrawn) * self.withdraw_cap as u128 / 100;
+
+    cap.saturating_sub(withdrawn)
+  }
+
+  pub(crate) fn internal_use_withdraw_limit(&mut self, amount: Balance) {
+    if amount > self.internal_withdraw_limit_left() {
+      env::panic_str("Withdraw limit for the window is reached, try later");
+    }
+
+    if date_now() >= self.withdraw_window_start + self.withdraw_window {
+      self.withdraw_window_start = date_now();
+      self.withdraw_window_amount = U128::from(0);
+    }
+
+    self.withdraw_window_amount = U128::from(self.withdraw_window_amount.0 + amount);
+  }
+}
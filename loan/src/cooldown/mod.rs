@@ -0,0 +1,5 @@
+mod cooldown_impl;
+mod cooldown;
+mod internal;
+
+pub use self::cooldown::{LoanFactoryCooldown, Unbonding};
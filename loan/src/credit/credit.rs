@@ -0,0 +1,31 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::json_types::U128;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use crate::meta::{JsonCredit, JsonCreditConfig};
+
+/// Closed loans of a borrower, bought out and cancelled loans are not counted
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CreditHistory {
+  pub on_time: u64,
+  pub late: u64,
+  pub defaults: u64,
+}
+
+/// Score of `full_score` and more gives the whole shift, a negative score takes it away the same way.
+/// `max_ltv_shift` is in percent points of the collateral price, `max_fee_shift` in percent of the commission.
+/// Loans repaid on time count only from `min_principal`
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CreditConfig {
+  pub max_ltv_shift: u64,
+  pub max_fee_shift: u64,
+  pub full_score: u64,
+  pub max_defaults: Option<u64>,
+  pub min_principal: Balance,
+}
+
+pub trait LoanFactoryCredit {
+  fn loan_set_credit_config(&mut self, max_ltv_shift: u64, max_fee_shift: u64, full_score: u64, max_defaults: Option<u64>, min_principal: U128);
+
+  fn loan_credit_config(&self) -> JsonCreditConfig;
+  fn loan_credit_of(&self, account_id: AccountId) -> JsonCredit;
+}
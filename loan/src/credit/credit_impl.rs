@@ -0,0 +1,65 @@
+use crate::base::LoanFactory;
+use crate::credit::{CreditConfig, LoanFactoryCredit};
+use crate::meta::{JsonCredit, JsonCreditConfig};
+use crate::event::LoanCreditConfigUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId};
+
+const MAX_LTV_SHIFT: u64 = 50;
+
+impl LoanFactoryCredit for LoanFactory {
+  fn loan_set_credit_config(&mut self, max_ltv_shift: u64, max_fee_shift: u64, full_score: u64, max_defaults: Option<u64>, min_principal: U128) {
+    self.assert_owner();
+
+    if max_ltv_shift > MAX_LTV_SHIFT {
+      env::panic_str(&format!("Max ltv shift is {}", MAX_LTV_SHIFT));
+    }
+    if max_fee_shift > 100 {
+      env::panic_str("Max fee shift is 100");
+    }
+    if full_score == 0 && (max_ltv_shift > 0 || max_fee_shift > 0) {
+      env::panic_str("Full score should be positive");
+    }
+
+    self.credit_config = CreditConfig {
+      max_ltv_shift,
+      max_fee_shift,
+      full_score,
+      max_defaults,
+      min_principal: min_principal.0,
+    };
+
+    LoanCreditConfigUpdate {
+      max_ltv_shift: &max_ltv_shift,
+      max_fee_shift: &max_fee_shift,
+      full_score: &full_score,
+      max_defaults: max_defaults.as_ref(),
+      min_principal: &min_principal,
+    }.emit();
+  }
+
+  fn loan_credit_config(&self) -> JsonCreditConfig {
+    JsonCreditConfig {
+      max_ltv_shift: self.credit_config.max_ltv_shift,
+      max_fee_shift: self.credit_config.max_fee_shift,
+      full_score: self.credit_config.full_score,
+      max_defaults: self.credit_config.max_defaults,
+      min_principal: U128::from(self.credit_config.min_principal),
+    }
+  }
+
+  fn loan_credit_of(&self, account_id: AccountId) -> JsonCredit {
+    let credit = self.credit_by_account.get(&account_id).unwrap_or_default();
+
+    JsonCredit {
+      account_id: account_id.clone(),
+      on_time: credit.on_time,
+      late: credit.late,
+      defaults: credit.defaults,
+      score: self.internal_credit_score(&account_id),
+      ltv_shift: self.internal_ltv_shift(&account_id),
+      commission: self.internal_credit_commission(&account_id),
+      restricted: self.internal_is_credit_restricted(&account_id),
+    }
+  }
+}
@@ -0,0 +1,113 @@
+use crate::base::LoanFactory;
+use crate::archive::{LoanOutcome, LoanRecord};
+use near_sdk::{env, AccountId};
+
+const MIN_PERCENT: u64 = 1;
+const MAX_PERCENT: u64 = 50;
+const ON_TIME_SCORE: i64 = 1;
+const LATE_SCORE: i64 = -2;
+const DEFAULT_SCORE: i64 = -5;
+
+impl LoanFactory {
+  pub(crate) fn internal_credit_score(&self, account_id: &AccountId) -> i64 {
+    let credit = self.credit_by_account.get(account_id).unwrap_or_default();
+
+    credit.on_time as i64 * ON_TIME_SCORE + credit.late as i64 * LATE_SCORE + credit.defaults as i64 * DEFAULT_SCORE
+  }
+
+  /// `max_shift` scaled by the score, from `-max_shift` to `max_shift`
+  pub(crate) fn internal_scale_shift(score: i64, full_score: u64, max_shift: u64) -> i64 {
+    let full_score = full_score as i64;
+
+    if full_score == 0 {
+      return 0;
+    }
+
+    score.clamp(-full_score, full_score) * max_shift as i64 / full_score
+  }
+
+  /// Shifted `percent` stays in the bounds of the owner settings
+  pub(crate) fn internal_shift_percent(percent: u64, ltv_shift: i64) -> u64 {
+    (percent as i64 - ltv_shift).clamp(MIN_PERCENT as i64, MAX_PERCENT as i64) as u64
+  }
+
+  fn internal_credit_shift(&self, account_id: &AccountId, max_shift: u64) -> i64 {
+    LoanFactory::internal_scale_shift(self.internal_credit_score(account_id), self.credit_config.full_score, max_shift)
+  }
+
+  pub(crate) fn internal_ltv_shift(&self, account_id: &AccountId) -> i64 {
+    self.internal_credit_shift(account_id, self.credit_config.max_ltv_shift)
+  }
+
+  /// Collateral `percent` kept by the pool, good borrowers get a smaller one
+  pub(crate) fn internal_credit_percent(&self, account_id: &AccountId, percent: u64) -> u64 {
+    LoanFactory::internal_shift_percent(percent, self.internal_ltv_shift(account_id))
+  }
+
+  pub(crate) fn internal_credit_commission(&self, account_id: &AccountId) -> u128 {
+    let fee_shift = self.internal_credit_shift(account_id, self.credit_config.max_fee_shift);
+
+    self.commission * (100 - fee_shift) as u128 / 100
+  }
+
+  pub(crate) fn internal_is_credit_restricted(&self, account_id: &AccountId) -> bool {
+    self.credit_config.max_defaults.is_some_and(|max_defaults| {
+      self.credit_by_account.get(account_id).is_some_and(|credit| credit.defaults >= max_defaults)
+    })
+  }
+
+  pub(crate) fn assert_credit(&self, account_id: &AccountId) {
+    if self.internal_is_credit_restricted(account_id) {
+      env::panic_str("Borrower is restricted after defaults");
+    }
+  }
+
+  /// Counts a closed loan to the borrower, `count` is false when the closing is reverted.
+  /// On time loans below `min_principal` are not counted, so small loans can not build the score
+  pub(crate) fn internal_update_credit(&mut self, record: &LoanRecord, count: bool) {
+    let mut credit = self.credit_by_account.get(&record.borrower_id).unwrap_or_default();
+
+    let counter = match record.outcome {
+      LoanOutcome::Repaid if record.closed_at.is_some_and(|closed_at| closed_at > record.expire_date) => &mut credit.late,
+      LoanOutcome::Repaid if record.terms.principal < self.credit_config.min_principal => return,
+      LoanOutcome::Repaid => &mut credit.on_time,
+      LoanOutcome::Defaulted | LoanOutcome::Liquidated => &mut credit.defaults,
+      LoanOutcome::Open | LoanOutcome::BoughtOut | LoanOutcome::Cancelled => return,
+    };
+
+    if count {
+      *counter += 1;
+    } else {
+      *counter -= 1;
+    }
+
+    self.credit_by_account.insert(&record.borrower_id, &credit);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn scale_shift_is_proportional_to_score() {
+    assert_eq!(LoanFactory::internal_scale_shift(0, 5, 10), 0);
+    assert_eq!(LoanFactory::internal_scale_shift(2, 5, 10), 4);
+    assert_eq!(LoanFactory::internal_scale_shift(-2, 5, 10), -4);
+  }
+
+  #[test]
+  fn scale_shift_is_capped_by_full_score() {
+    assert_eq!(LoanFactory::internal_scale_shift(100, 5, 10), 10);
+    assert_eq!(LoanFactory::internal_scale_shift(-100, 5, 10), -10);
+    assert_eq!(LoanFactory::internal_scale_shift(100, 0, 10), 0);
+  }
+
+  #[test]
+  fn shift_percent_stays_in_bounds() {
+    assert_eq!(LoanFactory::internal_shift_percent(20, 5), 15);
+    assert_eq!(LoanFactory::internal_shift_percent(20, -5), 25);
+    assert_eq!(LoanFactory::internal_shift_percent(5, 10), MIN_PERCENT);
+    assert_eq!(LoanFactory::internal_shift_percent(45, -10), MAX_PERCENT);
+  }
+}
@@ -0,0 +1,5 @@
+mod credit_impl;
+mod credit;
+mod internal;
+
+pub use self::credit::{LoanFactoryCredit, CreditConfig, CreditHistory};
@@ -0,0 +1,35 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::base::{ContractId, PoolId, TokenId};
+use crate::tranche::Tranche;
+
+/// `msg` of `ft_transfer_call` sent by the pool currency contract
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum LoanFtMessage {
+  Deposit {
+    #[serde(default)]
+    pool_id: Option<PoolId>,
+    #[serde(default)]
+    tranche: Option<Tranche>,
+    #[serde(default)]
+    lock_days: Option<u64>,
+  },
+  Pay { token_id: TokenId, contract_id: ContractId },
+  Buyout { token_id: TokenId, contract_id: ContractId },
+  NoteBuy { token_id: TokenId, contract_id: ContractId },
+  KeeperFund {
+    #[serde(default)]
+    pool_id: Option<PoolId>,
+  },
+}
+
+pub trait LoanFactoryCurrency {
+  fn loan_set_currency(&mut self, currency: Option<AccountId>);
+  fn loan_currency(&self) -> Option<AccountId>;
+
+  fn loan_payout_claim(&mut self);
+  fn loan_payout_of(&self, account_id: AccountId) -> U128;
+}
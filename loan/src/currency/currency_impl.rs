@@ -0,0 +1,69 @@
+use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, PromiseOrValue};
+use crate::base::LoanFactory;
+use crate::currency::{LoanFactoryCurrency, LoanFtMessage};
+use crate::event::{LoanCurrencyUpdate, LoanPayoutClaim};
+
+impl LoanFactoryCurrency for LoanFactory {
+  fn loan_set_currency(&mut self, currency: Option<AccountId>) {
+    self.assert_owner();
+
+    if self.total_balance.0 > 0 || self.total_loan.0 > 0 || self.total_rewards_pool.0 > 0 || self.total_payout.0 > 0 {
+      env::panic_str("Pool is not empty");
+    }
+
+    self.currency = currency;
+
+    LoanCurrencyUpdate {
+      currency: self.currency.as_ref(),
+    }.emit();
+  }
+
+  fn loan_currency(&self) -> Option<AccountId> {
+    self.currency.clone()
+  }
+
+  fn loan_payout_claim(&mut self) {
+    let account_id = env::predecessor_account_id();
+    let payout = self.payout_by_account.remove(&account_id).unwrap_or(0);
+
+    if payout == 0 {
+      env::panic_str("Nothing to claim");
+    }
+
+    self.total_payout = U128::from(self.total_payout.0 - payout);
+    self.internal_send_payout(&account_id, payout);
+
+    LoanPayoutClaim {
+      account_id: &account_id,
+      amount: &U128::from(payout),
+    }.emit();
+  }
+
+  fn loan_payout_of(&self, account_id: AccountId) -> U128 {
+    U128::from(self.payout_by_account.get(&account_id).unwrap_or(0))
+  }
+}
+
+impl FungibleTokenReceiver for LoanFactory {
+  fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+    let currency = self.currency.clone().unwrap_or_else(|| env::panic_str("Pool currency is NEAR"));
+
+    if env::predecessor_account_id() != currency {
+      env::panic_str("Token not accepted");
+    }
+
+    let message: LoanFtMessage = near_sdk::serde_json::from_str(&msg).unwrap_or_else(|_| env::panic_str("Invalid msg"));
+
+    match message {
+      LoanFtMessage::Deposit { tranche, lock_days, .. } => self.internal_deposit(&sender_id, amount, tranche, lock_days),
+      LoanFtMessage::Pay { token_id, contract_id } => self.internal_nft_pay(&sender_id, token_id, contract_id, amount.0),
+      LoanFtMessage::Buyout { token_id, contract_id } => self.internal_nft_buyout(&sender_id, token_id, contract_id, amount.0),
+      LoanFtMessage::NoteBuy { token_id, contract_id } => self.internal_note_buy(&sender_id, token_id, contract_id, amount.0),
+      LoanFtMessage::KeeperFund { .. } => self.internal_fund_keeper_reserve(&sender_id, amount.0),
+    }
+
+    PromiseOrValue::Value(U128::from(0))
+  }
+}
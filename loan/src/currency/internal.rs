@@ -0,0 +1,61 @@
+use crate::base::{LoanFactory, PoolId};
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, AccountId, Balance, Gas, Promise};
+
+const GAS_FOR_FT_TRANSFER: Gas = Gas(10_000_000_000_000);
+const CALLBACK_ON_PAYOUT: Gas = Gas(10_000_000_000_000);
+const ONE_YOCTO: Balance = 1;
+
+#[ext_contract(ext_self)]
+pub trait ExtSelf {
+  fn on_transfer_payout(&mut self, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>);
+}
+
+#[ext_contract(ext_ft)]
+pub trait FungibleToken {
+  fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
+
+impl LoanFactory {
+  pub(crate) fn assert_native_currency(&self) {
+    if let Some(currency) = &self.currency {
+      env::panic_str(&format!("Pool currency is {}, use ft_transfer_call", currency));
+    }
+  }
+
+  /// Sends pool currency: native NEAR or `ft_transfer` on the currency contract
+  pub(crate) fn internal_send(&self, receiver_id: &AccountId, amount: Balance) -> Promise {
+    match &self.currency {
+      Some(currency) => ext_ft::ft_transfer(
+        receiver_id.clone(),
+        U128::from(amount),
+        None,
+
+        currency.clone(),
+        ONE_YOCTO,
+        GAS_FOR_FT_TRANSFER,
+      ),
+      None => Promise::new(receiver_id.clone()).transfer(amount),
+    }
+  }
+
+  /// A failed payout is kept for the receiver and can be taken with `loan_payout_claim`
+  pub(crate) fn internal_send_payout(&self, receiver_id: &AccountId, amount: Balance) -> Promise {
+    self.internal_send(receiver_id, amount)
+      .then(ext_self::on_transfer_payout(
+        U128::from(amount),
+        receiver_id.clone(),
+        self.pool_id.clone(),
+        env::current_account_id(),
+        0,
+        CALLBACK_ON_PAYOUT,
+      ))
+  }
+
+  pub(crate) fn internal_increase_payout(&mut self, account_id: &AccountId, amount: Balance) {
+    let payout = self.payout_by_account.get(account_id).unwrap_or(0);
+
+    self.payout_by_account.insert(account_id, &(payout + amount));
+    self.total_payout = U128::from(self.total_payout.0 + amount);
+  }
+}
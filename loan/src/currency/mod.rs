@@ -0,0 +1,5 @@
+mod currency_impl;
+mod currency;
+mod internal;
+
+pub use self::currency::{LoanFactoryCurrency, LoanFtMessage};
@@ -1,7 +1,8 @@
-use near_sdk::{env, AccountId};
+use near_sdk::{env, AccountId, PublicKey};
 use serde::Serialize;
 use near_sdk::json_types::U128;
-use crate::base::TokenId;
+use crate::base::{PoolId, TokenId};
+use crate::tier::Tier;
 
 // storage
 
@@ -56,6 +57,111 @@ impl LoanFtClaimRewards<'_> {
   }
 }
 
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWithdrawQueue<'a> {
+  pub account_id: &'a AccountId,
+  pub amount: &'a U128,
+}
+
+impl LoanWithdrawQueue<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWithdrawQueue<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWithdrawQueue(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWithdrawQueueCancel<'a> {
+  pub account_id: &'a AccountId,
+}
+
+impl LoanWithdrawQueueCancel<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWithdrawQueueCancel<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWithdrawQueueCancel(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWithdrawUnbond<'a> {
+  pub account_id: &'a AccountId,
+  pub amount: &'a U128,
+  pub unlock_epoch_height: &'a u64,
+}
+
+impl LoanWithdrawUnbond<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWithdrawUnbond<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWithdrawUnbond(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWithdrawLimitsUpdate<'a> {
+  pub unbonding_epochs: &'a u64,
+  pub withdraw_cap: &'a u64,
+  pub withdraw_window: &'a u64,
+}
+
+impl LoanWithdrawLimitsUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWithdrawLimitsUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWithdrawLimitsUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanReserveUpdate<'a> {
+  pub reserve_ratio: &'a u64,
+  pub max_utilization: &'a u64,
+}
+
+impl LoanReserveUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanReserveUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanReserveUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanBorrowLimitUpdate<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_id: Option<&'a AccountId>,
+  pub max_principal: Option<&'a U128>,
+  pub max_loans: Option<&'a u64>,
+}
+
+impl LoanBorrowLimitUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanBorrowLimitUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanBorrowLimitUpdate(data)).emit()
+  }
+}
+
 // whitelist
 
 #[must_use]
@@ -108,6 +214,344 @@ impl LoanWhitelistUpdatePrice<'_> {
   }
 }
 
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWhitelistUpdateExposureCap<'a> {
+  pub contract_id: &'a AccountId,
+  pub max_principal: Option<&'a U128>,
+  pub max_share: Option<&'a u64>,
+}
+
+impl LoanWhitelistUpdateExposureCap<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWhitelistUpdateExposureCap<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWhitelistUpdateExposureCap(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWhitelistUpdateOracle<'a> {
+  pub contract_id: &'a AccountId,
+  pub oracle_id: Option<&'a AccountId>,
+  pub max_age: &'a u64,
+}
+
+impl LoanWhitelistUpdateOracle<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWhitelistUpdateOracle<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWhitelistUpdateOracle(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPriceFeedUpdate<'a> {
+  pub contract_id: &'a AccountId,
+  pub reporters: &'a Vec<AccountId>,
+  pub quorum: &'a u64,
+  pub max_deviation: &'a u64,
+}
+
+impl LoanPriceFeedUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPriceFeedUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPriceFeedUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPriceReport<'a> {
+  pub contract_id: &'a AccountId,
+  pub reporter_id: &'a AccountId,
+  pub price: &'a U128,
+}
+
+impl LoanPriceReport<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPriceReport<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPriceReport(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPriceKeyUpdate<'a> {
+  pub public_key: &'a PublicKey,
+  pub reporter_id: Option<&'a AccountId>,
+}
+
+impl LoanPriceKeyUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPriceKeyUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPriceKeyUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanQuoteMaxAgeUpdate<'a> {
+  pub max_age: &'a u64,
+}
+
+impl LoanQuoteMaxAgeUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanQuoteMaxAgeUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanQuoteMaxAgeUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanTwapUpdate<'a> {
+  pub contract_id: &'a AccountId,
+  pub window: Option<&'a u64>,
+}
+
+impl LoanTwapUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanTwapUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanTwapUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanWhitelistUpdateTiers<'a> {
+  pub contract_id: &'a AccountId,
+  pub tiers: &'a Vec<Tier>,
+}
+
+impl LoanWhitelistUpdateTiers<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanWhitelistUpdateTiers<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanWhitelistUpdateTiers(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPriceManagerUpdate<'a> {
+  pub account_id: &'a AccountId,
+  pub enabled: &'a bool,
+}
+
+impl LoanPriceManagerUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPriceManagerUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPriceManagerUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNftAppraisal<'a> {
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub price: Option<&'a U128>,
+  pub percent: Option<&'a u64>,
+}
+
+impl LoanNftAppraisal<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNftAppraisal<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNftAppraisal(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanAppraisalCapUpdate<'a> {
+  pub max_multiple: &'a u64,
+}
+
+impl LoanAppraisalCapUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanAppraisalCapUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanAppraisalCapUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanHealthThresholdsUpdate<'a> {
+  pub maintenance_health: &'a u64,
+  pub liquidation_health: Option<&'a u64>,
+}
+
+impl LoanHealthThresholdsUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanHealthThresholdsUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanHealthThresholdsUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanMarginCall<'a> {
+  pub owner_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub health: &'a u64,
+  pub debt: &'a U128,
+  pub value: &'a U128,
+}
+
+impl LoanMarginCall<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanMarginCall<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanMarginCall(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNftLiquidate<'a> {
+  pub old_owner_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub health: &'a u64,
+}
+
+impl LoanNftLiquidate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNftLiquidate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNftLiquidate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanKeeperBountyUpdate<'a> {
+  pub bounty: &'a U128,
+}
+
+impl LoanKeeperBountyUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanKeeperBountyUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanKeeperBountyUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanKeeperReserveFund<'a> {
+  pub account_id: &'a AccountId,
+  pub amount: &'a U128,
+}
+
+impl LoanKeeperReserveFund<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanKeeperReserveFund<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanKeeperReserveFund(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanKeeperLiquidate<'a> {
+  pub keeper_id: &'a AccountId,
+  pub liquidated: &'a u64,
+  pub bounty: &'a U128,
+}
+
+impl LoanKeeperLiquidate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanKeeperLiquidate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanKeeperLiquidate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanCreditConfigUpdate<'a> {
+  pub max_ltv_shift: &'a u64,
+  pub max_fee_shift: &'a u64,
+  pub full_score: &'a u64,
+  pub max_defaults: Option<&'a u64>,
+  pub min_principal: &'a U128,
+}
+
+impl LoanCreditConfigUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanCreditConfigUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanCreditConfigUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanTermsUpdate<'a> {
+  pub duration: &'a u64,
+  pub grace_period: &'a u64,
+  pub penalty_rate: &'a u64,
+}
+
+impl LoanTermsUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanTermsUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanTermsUpdate(data)).emit()
+  }
+}
+
 // base
 
 #[must_use]
@@ -186,6 +630,236 @@ impl LoanNftClaimExpired<'_> {
   }
 }
 
+// buyout
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNftBuyoutList<'a> {
+  pub owner_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub price: &'a U128,
+}
+
+impl LoanNftBuyoutList<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNftBuyoutList<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNftBuyoutList(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNftBuyoutCancel<'a> {
+  pub owner_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+}
+
+impl LoanNftBuyoutCancel<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNftBuyoutCancel<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNftBuyoutCancel(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNftBuyout<'a> {
+  pub owner_id: &'a AccountId,
+  pub buyer_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub price: &'a U128,
+  pub loan_amount: &'a U128,
+}
+
+impl LoanNftBuyout<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNftBuyout<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNftBuyout(data)).emit()
+  }
+}
+
+// currency
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanCurrencyUpdate<'a> {
+  pub currency: Option<&'a AccountId>,
+}
+
+impl LoanCurrencyUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanCurrencyUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanCurrencyUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPayoutClaim<'a> {
+  pub account_id: &'a AccountId,
+  pub amount: &'a U128,
+}
+
+impl LoanPayoutClaim<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPayoutClaim<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPayoutClaim(data)).emit()
+  }
+}
+
+// tranche
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanTrancheConfigUpdate<'a> {
+  pub senior_max_apr: &'a u64,
+  pub junior_fee_multiplier: &'a u64,
+}
+
+impl LoanTrancheConfigUpdate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanTrancheConfigUpdate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanTrancheConfigUpdate(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanLoss<'a> {
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub junior_loss: &'a U128,
+  pub senior_loss: &'a U128,
+}
+
+impl LoanLoss<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanLoss<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanLoss(data)).emit()
+  }
+}
+
+// pool
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanPoolCreate<'a> {
+  pub pool_id: &'a String,
+  pub owner_id: &'a AccountId,
+  pub commission: &'a u128,
+}
+
+impl LoanPoolCreate<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanPoolCreate<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanPoolCreate(data)).emit()
+  }
+}
+
+// note
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNoteBuy<'a> {
+  pub buyer_id: &'a AccountId,
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub amount: &'a U128,
+}
+
+impl LoanNoteBuy<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNoteBuy<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNoteBuy(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNoteList<'a> {
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+  pub price: &'a U128,
+}
+
+impl LoanNoteList<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNoteList<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNoteList(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanNoteCancel<'a> {
+  pub contract_id: &'a AccountId,
+  pub token_id: &'a TokenId,
+}
+
+impl LoanNoteCancel<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanNoteCancel<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanNoteCancel(data)).emit()
+  }
+}
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct LoanShareTransfer<'a> {
+  pub pool_id: &'a PoolId,
+  pub old_owner_id: &'a AccountId,
+  pub new_owner_id: &'a AccountId,
+  pub amount: &'a U128,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub memo: Option<&'a str>,
+}
+
+impl LoanShareTransfer<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [LoanShareTransfer<'a>]) {
+    new_loan_v1(NepLoanEventKind::LoanShareTransfer(data)).emit()
+  }
+}
+
 //
 
 #[derive(Serialize, Debug)]
@@ -212,6 +886,102 @@ enum NepLoanEventKind<'a> {
   LoanNftPay(&'a [LoanNftPay<'a>]),
   LoanNftClaim(&'a [LoanNftClaim<'a>]),
   LoanNftClaimExpired(&'a [LoanNftClaimExpired<'a>]),
+
+  LoanNftBuyoutList(&'a [LoanNftBuyoutList<'a>]),
+  LoanNftBuyoutCancel(&'a [LoanNftBuyoutCancel<'a>]),
+  LoanNftBuyout(&'a [LoanNftBuyout<'a>]),
+
+  LoanNoteBuy(&'a [LoanNoteBuy<'a>]),
+  LoanNoteList(&'a [LoanNoteList<'a>]),
+  LoanNoteCancel(&'a [LoanNoteCancel<'a>]),
+  LoanShareTransfer(&'a [LoanShareTransfer<'a>]),
+
+  LoanCurrencyUpdate(&'a [LoanCurrencyUpdate<'a>]),
+  LoanPayoutClaim(&'a [LoanPayoutClaim<'a>]),
+
+  LoanPoolCreate(&'a [LoanPoolCreate<'a>]),
+
+  LoanTrancheConfigUpdate(&'a [LoanTrancheConfigUpdate<'a>]),
+  LoanLoss(&'a [LoanLoss<'a>]),
+
+  LoanWithdrawQueue(&'a [LoanWithdrawQueue<'a>]),
+  LoanWithdrawQueueCancel(&'a [LoanWithdrawQueueCancel<'a>]),
+
+  LoanWithdrawUnbond(&'a [LoanWithdrawUnbond<'a>]),
+  LoanWithdrawLimitsUpdate(&'a [LoanWithdrawLimitsUpdate<'a>]),
+
+  LoanReserveUpdate(&'a [LoanReserveUpdate<'a>]),
+
+  LoanWhitelistUpdateExposureCap(&'a [LoanWhitelistUpdateExposureCap<'a>]),
+
+  LoanBorrowLimitUpdate(&'a [LoanBorrowLimitUpdate<'a>]),
+
+  LoanWhitelistUpdateOracle(&'a [LoanWhitelistUpdateOracle<'a>]),
+
+  LoanPriceFeedUpdate(&'a [LoanPriceFeedUpdate<'a>]),
+  LoanPriceReport(&'a [LoanPriceReport<'a>]),
+  LoanPriceKeyUpdate(&'a [LoanPriceKeyUpdate<'a>]),
+  LoanQuoteMaxAgeUpdate(&'a [LoanQuoteMaxAgeUpdate<'a>]),
+
+  LoanTwapUpdate(&'a [LoanTwapUpdate<'a>]),
+
+  LoanWhitelistUpdateTiers(&'a [LoanWhitelistUpdateTiers<'a>]),
+
+  LoanPriceManagerUpdate(&'a [LoanPriceManagerUpdate<'a>]),
+  LoanNftAppraisal(&'a [LoanNftAppraisal<'a>]),
+  LoanAppraisalCapUpdate(&'a [LoanAppraisalCapUpdate<'a>]),
+
+  LoanHealthThresholdsUpdate(&'a [LoanHealthThresholdsUpdate<'a>]),
+  LoanMarginCall(&'a [LoanMarginCall<'a>]),
+  LoanNftLiquidate(&'a [LoanNftLiquidate<'a>]),
+
+  LoanKeeperBountyUpdate(&'a [LoanKeeperBountyUpdate<'a>]),
+  LoanKeeperReserveFund(&'a [LoanKeeperReserveFund<'a>]),
+  LoanKeeperLiquidate(&'a [LoanKeeperLiquidate<'a>]),
+
+  LoanCreditConfigUpdate(&'a [LoanCreditConfigUpdate<'a>]),
+
+  LoanTermsUpdate(&'a [LoanTermsUpdate<'a>]),
+}
+
+// nep141
+
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+pub struct FtTransfer<'a> {
+  pub old_owner_id: &'a AccountId,
+  pub new_owner_id: &'a AccountId,
+  pub amount: &'a U128,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub memo: Option<&'a str>,
+}
+
+impl FtTransfer<'_> {
+  pub fn emit(self) {
+    Self::emit_many(&[self])
+  }
+
+  pub fn emit_many<'a>(data: &'a [FtTransfer<'a>]) {
+    new_141_v1(Nep141EventKind::FtTransfer(data)).emit()
+  }
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct Nep141Event<'a> {
+  version: &'static str,
+  #[serde(flatten)]
+  event_kind: Nep141EventKind<'a>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+enum Nep141EventKind<'a> {
+  FtTransfer(&'a [FtTransfer<'a>]),
+}
+
+fn new_141_v1(event_kind: Nep141EventKind) -> NearEvent {
+  NearEvent::Nep141(Nep141Event { version: "1.0.0", event_kind })
 }
 
 fn new_loan<'a>(version: &'static str, event_kind: NepLoanEventKind<'a>) -> NearEvent<'a> {
@@ -230,7 +1000,7 @@ fn new_loan_v1(event_kind: NepLoanEventKind) -> NearEvent {
 #[serde(rename_all = "snake_case")]
 pub(crate) enum NearEvent<'a> {
     // Nep171(crate::non_fungible_token::events::Nep171Event<'a>),
-    // Nep141(crate::base::Nep141Event<'a>),
+  Nep141(Nep141Event<'a>),
   NepLoan(NepLoanEvent<'a>),
 }
 
@@ -0,0 +1,6 @@
+use near_sdk::json_types::U128;
+use crate::meta::JsonLoan;
+
+pub trait LoanFactoryExpiry {
+  fn loan_expiring_between(&self, from: u64, to: u64, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoan>;
+}
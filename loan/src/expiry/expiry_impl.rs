@@ -0,0 +1,21 @@
+use crate::base::LoanFactory;
+use crate::expiry::LoanFactoryExpiry;
+use crate::meta::JsonLoan;
+use near_sdk::json_types::U128;
+use near_sdk::require;
+
+impl LoanFactoryExpiry for LoanFactory {
+  /// Loans with `from <= expired_at <= to`, ordered by expire date
+  fn loan_expiring_between(&self, from: u64, to: u64, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoan> {
+    require!(from <= to, "Invalid range");
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+
+    self.internal_loans_expiring_between(from, to)
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|contract_token_id| self.enum_get_loan(&contract_token_id))
+      .collect()
+  }
+}
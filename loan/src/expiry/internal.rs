@@ -0,0 +1,15 @@
+use crate::base::{LoanFactory, TokenId};
+use std::ops::Bound;
+
+impl LoanFactory {
+  pub(crate) fn internal_loans_expiring_between(&self, from: u64, to: u64) -> impl Iterator<Item = TokenId> + '_ {
+    let to = match to.checked_add(1) {
+      Some(to) => Bound::Excluded((to, TokenId::new())),
+      None => Bound::Unbounded,
+    };
+
+    self.loan_by_expiry
+      .range((Bound::Included((from, TokenId::new())), to))
+      .map(|((_, contract_token_id), _)| contract_token_id)
+  }
+}
@@ -0,0 +1,5 @@
+mod expiry_impl;
+mod expiry;
+mod internal;
+
+pub use self::expiry::LoanFactoryExpiry;
@@ -0,0 +1,36 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::base::ContractId;
+use crate::meta::JsonPriceFeed;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PriceSubmission {
+  pub reporter_id: AccountId,
+  pub price: Balance,
+  pub timestamp: u64,
+}
+
+/// Median that moved more than `max_deviation` and waits for a second reporter
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PendingPrice {
+  pub reporter_id: AccountId,
+  pub price: Balance,
+}
+
+/// Authorized reporters of a collection floor price, latest submission per reporter
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PriceFeed {
+  pub reporters: Vec<AccountId>,
+  pub quorum: u64,
+  pub max_deviation: u64,
+  pub submissions: Vec<PriceSubmission>,
+  pub pending: Option<PendingPrice>,
+}
+
+pub trait LoanFactoryPriceFeed {
+  fn loan_nft_set_reporters(&mut self, contract_id: ContractId, reporters: Vec<AccountId>, quorum: u64, max_deviation: u64);
+  fn loan_nft_report_price(&mut self, contract_id: ContractId, price: U128);
+
+  fn loan_nft_price_feed(&self, contract_id: ContractId) -> Option<JsonPriceFeed>;
+}
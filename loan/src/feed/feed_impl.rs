@@ -0,0 +1,71 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::feed::{LoanFactoryPriceFeed, PriceFeed};
+use crate::meta::{JsonPriceFeed, JsonPriceSubmission};
+use crate::event::LoanPriceFeedUpdate;
+use crate::utils::date_now;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId};
+
+impl LoanFactoryPriceFeed for LoanFactory {
+  fn loan_nft_set_reporters(&mut self, contract_id: ContractId, reporters: Vec<AccountId>, quorum: u64, max_deviation: u64) {
+    self.assert_owner();
+
+    if reporters.is_empty() {
+      self.price_feed_by_contract.remove(&contract_id);
+    } else {
+      // a minority of reporters can not move the median alone
+      if quorum <= reporters.len() as u64 / 2 || quorum > reporters.len() as u64 {
+        env::panic_str("Quorum should be a majority of the reporters");
+      }
+      if max_deviation < 1 {
+        env::panic_str("Invalid max deviation");
+      }
+
+      // submissions of removed reporters are dropped
+      let submissions = self.price_feed_by_contract.get(&contract_id)
+        .map(|feed| feed.submissions)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|submission| reporters.contains(&submission.reporter_id))
+        .collect();
+
+      self.price_feed_by_contract.insert(&contract_id, &PriceFeed {
+        reporters: reporters.clone(),
+        quorum,
+        max_deviation,
+        submissions,
+        pending: None,
+      });
+    }
+
+    LoanPriceFeedUpdate {
+      contract_id: &contract_id,
+      reporters: &reporters,
+      quorum: &quorum,
+      max_deviation: &max_deviation,
+    }.emit();
+  }
+
+  fn loan_nft_report_price(&mut self, contract_id: ContractId, price: U128) {
+    self.internal_report_price(env::predecessor_account_id(), contract_id, price.0, date_now());
+  }
+
+  fn loan_nft_price_feed(&self, contract_id: ContractId) -> Option<JsonPriceFeed> {
+    self.price_feed_by_contract.get(&contract_id).map(|feed| JsonPriceFeed {
+      median: LoanFactory::internal_median_price(&feed).map(U128::from),
+      pending_price: feed.pending.as_ref().map(|pending| U128::from(pending.price)),
+      submissions: feed.submissions
+        .iter()
+        .map(|submission| JsonPriceSubmission {
+          reporter_id: submission.reporter_id.clone(),
+          price: U128::from(submission.price),
+          timestamp: submission.timestamp,
+        })
+        .collect(),
+      contract_id,
+      reporters: feed.reporters,
+      quorum: feed.quorum,
+      max_deviation: feed.max_deviation,
+    })
+  }
+}
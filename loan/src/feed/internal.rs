@@ -0,0 +1,157 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::feed::{PriceFeed, PendingPrice, PriceSubmission};
+use crate::event::{LoanPriceReport, LoanWhitelistUpdatePrice};
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+
+impl LoanFactory {
+  /// Replaces the latest submission of the reporter and stores the median once accepted
+  pub(crate) fn internal_report_price(&mut self, reporter_id: AccountId, contract_id: ContractId, price: Balance, timestamp: u64) {
+    let mut feed = self.price_feed_by_contract.get(&contract_id).expect("Not found price feed for current nft");
+
+    if !feed.reporters.contains(&reporter_id) {
+      env::panic_str("Unauthorized");
+    }
+    if price < 1 {
+      env::panic_str("Invalid price");
+    }
+
+    feed.submissions.retain(|submission| submission.reporter_id != reporter_id);
+    feed.submissions.push(PriceSubmission {
+      reporter_id: reporter_id.clone(),
+      price,
+      timestamp,
+    });
+
+    LoanPriceReport {
+      contract_id: &contract_id,
+      reporter_id: &reporter_id,
+      price: &U128::from(price),
+    }.emit();
+
+    let last_price = self.price_by_contract.get(&contract_id);
+    let accepted = LoanFactory::internal_accept_median(&mut feed, last_price, &reporter_id, price);
+
+    self.price_feed_by_contract.insert(&contract_id, &feed);
+
+    if let Some(accepted) = accepted {
+      let percent = self.percent_by_contract.get(&contract_id).expect("Not found percent for current nft");
+
+      self.internal_update_price(&contract_id, accepted);
+
+      LoanWhitelistUpdatePrice {
+        contract_id: &contract_id,
+        price: &U128::from(accepted),
+        percent: &percent,
+      }.emit();
+    }
+  }
+
+  /// Median of the latest submissions, `None` until the quorum is met
+  pub(crate) fn internal_median_price(feed: &PriceFeed) -> Option<Balance> {
+    if (feed.submissions.len() as u64) < feed.quorum {
+      return None;
+    }
+
+    let mut prices: Vec<Balance> = feed.submissions.iter().map(|submission| submission.price).collect();
+    prices.sort_unstable();
+
+    let middle = prices.len() / 2;
+
+    if prices.len().is_multiple_of(2) {
+      Some((prices[middle - 1] + prices[middle]) / 2)
+    } else {
+      Some(prices[middle])
+    }
+  }
+
+  pub(crate) fn internal_is_within_deviation(base: Balance, price: Balance, max_deviation: u64) -> bool {
+    base.abs_diff(price) * 100 <= base * (max_deviation as u128)
+  }
+
+  /// Median to store in `price_by_contract`. A move above `max_deviation` from the last accepted
+  /// price is kept as pending and accepted only when another reporter submits `price` close to it,
+  /// a recomputed median is not a confirmation since one outlier shifts it.
+  pub(crate) fn internal_accept_median(feed: &mut PriceFeed, last_price: Option<Balance>, reporter_id: &AccountId, price: Balance) -> Option<Balance> {
+    let median = Self::internal_median_price(feed)?;
+
+    let confirmed = last_price.is_none_or(|last_price| Self::internal_is_within_deviation(last_price, median, feed.max_deviation))
+      || feed.pending.as_ref().is_some_and(|pending| {
+        &pending.reporter_id != reporter_id && Self::internal_is_within_deviation(pending.price, price, feed.max_deviation)
+      });
+
+    if confirmed {
+      feed.pending = None;
+
+      Some(median)
+    } else {
+      feed.pending = Some(PendingPrice {
+        reporter_id: reporter_id.clone(),
+        price: median,
+      });
+
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::base::LoanFactory;
+  use crate::feed::{PriceFeed, PriceSubmission};
+  use near_sdk::{AccountId, Balance};
+
+  fn account(id: &str) -> AccountId {
+    AccountId::new_unchecked(format!("{}.testnet", id))
+  }
+
+  fn feed(prices: &[(&str, Balance)], quorum: u64) -> PriceFeed {
+    PriceFeed {
+      reporters: prices.iter().map(|(id, _)| account(id)).collect(),
+      quorum,
+      max_deviation: 10,
+      submissions: prices.iter().map(|(id, price)| PriceSubmission { reporter_id: account(id), price: *price, timestamp: 0 }).collect(),
+      pending: None,
+    }
+  }
+
+  #[test]
+  fn median_waits_for_quorum() {
+    assert_eq!(LoanFactory::internal_median_price(&feed(&[("a", 100)], 2)), None);
+  }
+
+  #[test]
+  fn median_of_odd_and_even_submissions() {
+    assert_eq!(LoanFactory::internal_median_price(&feed(&[("a", 300), ("b", 100), ("c", 200)], 2)), Some(200));
+    assert_eq!(LoanFactory::internal_median_price(&feed(&[("a", 300), ("b", 100)], 2)), Some(200));
+  }
+
+  #[test]
+  fn accept_median_within_deviation() {
+    let mut feed = feed(&[("a", 105), ("b", 100)], 2);
+
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("a"), 105), Some(102));
+    assert!(feed.pending.is_none());
+  }
+
+  #[test]
+  fn accept_median_needs_second_reporter_for_big_move() {
+    let mut feed = feed(&[("a", 200), ("b", 200)], 2);
+
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("a"), 200), None);
+    assert_eq!(feed.pending.as_ref().map(|pending| pending.price), Some(200));
+    // the same reporter can not confirm its own move
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("a"), 200), None);
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("b"), 200), Some(200));
+    assert!(feed.pending.is_none());
+  }
+
+  #[test]
+  fn accept_median_ignores_outlier_shifted_median() {
+    let mut feed = feed(&[("a", 10000), ("b", 100)], 2);
+
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("a"), 10000), None);
+    // the honest report leaves the median at the pending price, but does not confirm it
+    assert_eq!(LoanFactory::internal_accept_median(&mut feed, Some(100), &account("b"), 100), None);
+  }
+}
@@ -0,0 +1,5 @@
+mod feed_impl;
+mod feed;
+mod internal;
+
+pub use self::feed::{LoanFactoryPriceFeed, PriceFeed, PriceSubmission, PendingPrice};
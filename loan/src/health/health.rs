@@ -0,0 +1,13 @@
+use near_sdk::json_types::U128;
+use crate::base::{ContractId, TokenId};
+use crate::meta::{JsonHealthThresholds, JsonLoanHealth};
+
+pub trait LoanFactoryHealth {
+  fn loan_set_health_thresholds(&mut self, maintenance_health: u64, liquidation_health: Option<u64>);
+  fn loan_margin_call(&mut self, token_id: TokenId, contract_id: ContractId);
+  fn loan_nft_liquidate(&mut self, token_id: TokenId, contract_id: ContractId);
+
+  fn loan_health_thresholds(&self) -> JsonHealthThresholds;
+  fn loan_health_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanHealth;
+  fn loan_unhealthy(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanHealth>;
+}
@@ -0,0 +1,95 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::health::LoanFactoryHealth;
+use crate::archive::LoanOutcome;
+use crate::meta::{JsonHealthThresholds, JsonLoanHealth};
+use crate::event::{LoanHealthThresholdsUpdate, LoanMarginCall, LoanNftLiquidate};
+use near_sdk::json_types::U128;
+use near_sdk::{env, require};
+
+impl LoanFactoryHealth for LoanFactory {
+  fn loan_set_health_thresholds(&mut self, maintenance_health: u64, liquidation_health: Option<u64>) {
+    self.assert_owner();
+
+    if maintenance_health < 100 {
+      env::panic_str("Min maintenance health is 100");
+    }
+    // above 100 the pool would take the borrower's part of the collateral value
+    if liquidation_health.is_some_and(|liquidation_health| !(1..=100).contains(&liquidation_health) || liquidation_health >= maintenance_health) {
+      env::panic_str("Liquidation health should be from 1 to 100 and below the maintenance health");
+    }
+
+    self.maintenance_health = maintenance_health;
+    self.liquidation_health = liquidation_health;
+
+    LoanHealthThresholdsUpdate {
+      maintenance_health: &maintenance_health,
+      liquidation_health: liquidation_health.as_ref(),
+    }.emit();
+  }
+
+  fn loan_margin_call(&mut self, token_id: TokenId, contract_id: ContractId) {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+    let health = self.enum_get_loan_health(&contract_token_id);
+
+    if health.health.is_none_or(|health| health >= self.internal_maintenance_health_of(&contract_token_id)) {
+      env::panic_str("Loan is above the maintenance health");
+    }
+
+    LoanMarginCall {
+      owner_id: &health.owner_id,
+      contract_id: &contract_id,
+      token_id: &token_id,
+      health: &health.health.unwrap_or_default(),
+      debt: &health.debt,
+      value: &health.value,
+    }.emit();
+  }
+
+  fn loan_nft_liquidate(&mut self, token_id: TokenId, contract_id: ContractId) {
+    // thresholds set before the cap are treated as 100
+    let liquidation_health = self.liquidation_health.expect("Early liquidation is disabled").min(100);
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+    let health = self.internal_loan_health(&contract_token_id);
+
+    if health.is_none_or(|health| health >= liquidation_health) {
+      env::panic_str("Loan is above the liquidation health");
+    }
+
+    let owner_id = self.internal_default_loan(&token_id, &contract_id, LoanOutcome::Liquidated);
+
+    LoanNftLiquidate {
+      old_owner_id: &owner_id,
+      contract_id: &contract_id,
+      token_id: &token_id,
+      health: &health.unwrap_or_default(),
+    }.emit();
+  }
+
+  fn loan_health_thresholds(&self) -> JsonHealthThresholds {
+    JsonHealthThresholds {
+      maintenance_health: self.maintenance_health,
+      liquidation_health: self.liquidation_health,
+    }
+  }
+
+  fn loan_health_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanHealth {
+    self.enum_get_loan_health(&self.internal_get_token_id(&contract_id, &token_id))
+  }
+
+  fn loan_unhealthy(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoanHealth> {
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+
+    self.loan_date_by_nft
+      .iter()
+      .map(|(contract_token_id, _)| contract_token_id)
+      .filter(|contract_token_id| {
+        self.internal_loan_health(contract_token_id).is_some_and(|health| health < self.internal_maintenance_health_of(contract_token_id))
+      })
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|contract_token_id| self.enum_get_loan_health(&contract_token_id))
+      .collect()
+  }
+}
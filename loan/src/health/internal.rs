@@ -0,0 +1,70 @@
+use crate::base::{LoanFactory, TokenId};
+use crate::meta::JsonLoanHealth;
+use near_sdk::json_types::U128;
+use near_sdk::{AccountId, Balance};
+
+impl LoanFactory {
+  /// Current value of the collateral: appraisal, then the current price of the loan tier, then the
+  /// collection price. The price taken at loan time is used when the collection has no price anymore.
+  pub(crate) fn internal_collateral_value(&self, contract_token_id: &TokenId) -> Balance {
+    let arr = contract_token_id.split("||").collect::<Vec<&str>>();
+    let contract_id = AccountId::new_unchecked(arr[0].to_string());
+
+    if let Some(appraisal) = self.appraisal_by_nft.get(contract_token_id) {
+      return appraisal.price;
+    }
+
+    let tier_price = self.tier_by_nft.get(contract_token_id).and_then(|name| {
+      self.tiers_by_contract
+        .get(&contract_id)?
+        .into_iter()
+        .find(|tier| tier.name == name)
+        .map(|tier| tier.price.0)
+    });
+
+    tier_price
+      .or_else(|| self.price_by_contract.get(&contract_id))
+      .or_else(|| self.price_by_nft.get(contract_token_id))
+      .unwrap_or_default()
+  }
+
+  /// Collateral value to debt in percent, `None` when nothing is owed
+  pub(crate) fn internal_loan_health(&self, contract_token_id: &TokenId) -> Option<u64> {
+    let debt = self.internal_rest_of_loan(contract_token_id).0;
+
+    if debt == 0 {
+      return None;
+    }
+
+    let health = self.internal_collateral_value(contract_token_id) * 100 / debt;
+
+    Some(health.min(u64::MAX as u128) as u64)
+  }
+
+  /// Health when the loan was taken, the collateral price over the principal
+  pub(crate) fn internal_origination_health(&self, contract_token_id: &TokenId) -> Option<u64> {
+    let percent = self.percent_by_nft.get(contract_token_id)?;
+
+    Some(100 * 100 / (100 - percent))
+  }
+
+  /// A loan taken with a thinner margin than `maintenance_health` is unhealthy only below its own start
+  pub(crate) fn internal_maintenance_health_of(&self, contract_token_id: &TokenId) -> u64 {
+    self.internal_origination_health(contract_token_id)
+      .map_or(self.maintenance_health, |health| health.min(self.maintenance_health))
+  }
+
+  pub(crate) fn enum_get_loan_health(&self, contract_token_id: &TokenId) -> JsonLoanHealth {
+    let owner_id = self.owner_by_nft.get(contract_token_id).expect("Not found token owner");
+    let arr = contract_token_id.split("||").collect::<Vec<&str>>();
+
+    JsonLoanHealth {
+      token_id: arr[1].to_string(),
+      contract_id: AccountId::new_unchecked(arr[0].to_string()),
+      owner_id,
+      debt: self.internal_rest_of_loan(contract_token_id),
+      value: U128::from(self.internal_collateral_value(contract_token_id)),
+      health: self.internal_loan_health(contract_token_id),
+    }
+  }
+}
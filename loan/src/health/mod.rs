@@ -0,0 +1,5 @@
+mod health_impl;
+mod health;
+mod internal;
+
+pub use self::health::LoanFactoryHealth;
@@ -0,0 +1,26 @@
+use near_sdk::Balance;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::base::ContractId;
+use crate::meta::JsonPricePoint;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct PricePoint {
+  pub price: Balance,
+  pub timestamp: u64,
+}
+
+/// Ring buffer of the latest prices, `next` is the slot to overwrite once full
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct PriceHistory {
+  pub points: Vec<PricePoint>,
+  pub next: u64,
+}
+
+pub trait LoanFactoryPriceHistory {
+  fn loan_nft_set_twap(&mut self, contract_id: ContractId, window: Option<u64>);
+
+  fn loan_nft_price_history(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonPricePoint>;
+  fn loan_nft_twap(&self, contract_id: ContractId, window: Option<u64>) -> Option<U128>;
+  fn loan_nft_twap_window(&self, contract_id: ContractId) -> Option<u64>;
+}
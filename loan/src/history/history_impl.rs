@@ -0,0 +1,73 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::history::LoanFactoryPriceHistory;
+use crate::meta::JsonPricePoint;
+use crate::event::LoanTwapUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::{env, require};
+
+impl LoanFactoryPriceHistory for LoanFactory {
+  fn loan_nft_set_twap(&mut self, contract_id: ContractId, window: Option<u64>) {
+    self.assert_owner();
+
+    match window {
+      Some(window) => {
+        if window < 1 {
+          env::panic_str("Invalid twap window");
+        }
+
+        // prices set before the history existed start the buffer
+        if self.internal_price_points(&contract_id).is_empty() {
+          if let Some(price) = self.price_by_contract.get(&contract_id) {
+            self.internal_record_price(&contract_id, price);
+          }
+        }
+
+        self.twap_window_by_contract.insert(&contract_id, &window);
+      }
+      None => {
+        self.twap_window_by_contract.remove(&contract_id);
+      }
+    }
+
+    LoanTwapUpdate {
+      contract_id: &contract_id,
+      window: window.as_ref(),
+    }.emit();
+  }
+
+  fn loan_nft_price_history(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonPricePoint> {
+    let points = self.internal_price_points(&contract_id);
+
+    if points.is_empty() {
+      return vec![];
+    }
+
+    let limit = limit.map(|v| v as usize).unwrap_or(usize::MAX);
+    require!(limit != 0, "Cannot provide limit of 0.");
+    let start_index: u128 = from_index.map(From::from).unwrap_or_default();
+    require!(
+      points.len() as u128 > start_index,
+      "Out of bounds, please use a smaller from_index."
+    );
+
+    points
+      .into_iter()
+      .skip(start_index as usize)
+      .take(limit)
+      .map(|point| JsonPricePoint {
+        price: U128::from(point.price),
+        timestamp: point.timestamp,
+      })
+      .collect()
+  }
+
+  fn loan_nft_twap(&self, contract_id: ContractId, window: Option<u64>) -> Option<U128> {
+    let window = window.or_else(|| self.twap_window_by_contract.get(&contract_id))?;
+
+    self.internal_twap(&contract_id, window).map(U128::from)
+  }
+
+  fn loan_nft_twap_window(&self, contract_id: ContractId) -> Option<u64> {
+    self.twap_window_by_contract.get(&contract_id)
+  }
+}
@@ -0,0 +1,127 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::history::{PricePoint, PriceHistory};
+use crate::utils::date_now;
+use near_sdk::{env, Balance};
+
+const PRICE_HISTORY_SIZE: usize = 48;
+
+impl LoanFactory {
+  /// Sets the spot price and appends it to the history
+  pub(crate) fn internal_update_price(&mut self, contract_id: &ContractId, price: Balance) {
+    self.price_by_contract.insert(contract_id, &price);
+    self.internal_record_price(contract_id, price);
+  }
+
+  pub(crate) fn internal_record_price(&mut self, contract_id: &ContractId, price: Balance) {
+    let mut history = self.price_history_by_contract.get(contract_id).unwrap_or_default();
+    let point = PricePoint {
+      price,
+      timestamp: date_now(),
+    };
+
+    if history.points.len() < PRICE_HISTORY_SIZE {
+      history.points.push(point);
+    } else {
+      history.points[history.next as usize] = point;
+    }
+    history.next = (history.next + 1) % PRICE_HISTORY_SIZE as u64;
+
+    self.price_history_by_contract.insert(contract_id, &history);
+  }
+
+  /// History from the oldest point
+  pub(crate) fn internal_price_points(&self, contract_id: &ContractId) -> Vec<PricePoint> {
+    let PriceHistory { mut points, next } = self.price_history_by_contract.get(contract_id).unwrap_or_default();
+
+    if points.len() == PRICE_HISTORY_SIZE {
+      points.rotate_left(next as usize);
+    }
+
+    points
+  }
+
+  pub(crate) fn internal_twap(&self, contract_id: &ContractId, window: u64) -> Option<Balance> {
+    Self::internal_time_weighted_price(&self.internal_price_points(contract_id), date_now(), window)
+  }
+
+  /// Average of the prices over the last `window` ms, each price weighted by the time it was active.
+  /// The price active at the start of the window counts from the start.
+  pub(crate) fn internal_time_weighted_price(points: &[PricePoint], now: u64, window: u64) -> Option<Balance> {
+    let start = now.saturating_sub(window);
+
+    let mut weighted: Balance = 0;
+    let mut duration: u64 = 0;
+
+    for (index, point) in points.iter().enumerate() {
+      let from = point.timestamp.max(start);
+      let to = points.get(index + 1).map_or(now, |next| next.timestamp).max(start);
+
+      // seconds keep `price * duration` far from the u128 limit
+      let seconds = to / 1000 - from / 1000;
+
+      weighted += point.price * seconds as u128;
+      duration += seconds;
+    }
+
+    match duration {
+      // all prices are set within the last second
+      0 => points.last().map(|point| point.price),
+      _ => Some(weighted / duration as u128),
+    }
+  }
+
+  /// The ring buffer keeps `PRICE_HISTORY_SIZE` points, frequent updates push the start of the window out of it
+  pub(crate) fn internal_is_window_covered(&self, contract_id: &ContractId, window: u64) -> bool {
+    let start = date_now().saturating_sub(window);
+
+    self.internal_price_points(contract_id).first().is_some_and(|point| point.timestamp <= start)
+  }
+
+  /// Price for the LTV: twap when the collection has a window, spot otherwise.
+  /// Until the history covers the window the lower of twap and spot is used.
+  pub(crate) fn internal_loan_price(&self, contract_id: &ContractId) -> Balance {
+    let spot = self.price_by_contract.get(contract_id).unwrap_or_else(|| env::panic_str("Not found price for current nft"));
+
+    let window = match self.twap_window_by_contract.get(contract_id) {
+      Some(window) => window,
+      None => return spot,
+    };
+
+    match self.internal_twap(contract_id, window) {
+      Some(twap) if self.internal_is_window_covered(contract_id, window) => twap,
+      // a shorter history is not trusted above the spot price
+      Some(twap) => twap.min(spot),
+      None => spot,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::base::LoanFactory;
+  use crate::history::PricePoint;
+
+  fn points(points: &[(u128, u64)]) -> Vec<PricePoint> {
+    points.iter().map(|(price, timestamp)| PricePoint { price: *price, timestamp: *timestamp }).collect()
+  }
+
+  #[test]
+  fn twap_weights_prices_by_time() {
+    let points = points(&[(100, 0), (200, 30_000)]);
+
+    assert_eq!(LoanFactory::internal_time_weighted_price(&points, 40_000, 40_000), Some(125));
+  }
+
+  #[test]
+  fn twap_counts_active_price_from_window_start() {
+    let points = points(&[(100, 0), (300, 90_000)]);
+
+    assert_eq!(LoanFactory::internal_time_weighted_price(&points, 100_000, 20_000), Some(200));
+  }
+
+  #[test]
+  fn twap_of_recent_prices() {
+    assert_eq!(LoanFactory::internal_time_weighted_price(&points(&[(100, 10_000), (200, 10_500)]), 10_600, 1_000), Some(200));
+    assert_eq!(LoanFactory::internal_time_weighted_price(&[], 10_000, 1_000), None);
+  }
+}
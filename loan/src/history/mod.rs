@@ -0,0 +1,5 @@
+mod history_impl;
+mod history;
+mod internal;
+
+pub use self::history::{LoanFactoryPriceHistory, PriceHistory, PricePoint};
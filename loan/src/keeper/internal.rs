@@ -0,0 +1,80 @@
+use crate::base::{LoanFactory, PoolId, TokenId};
+use crate::event::LoanKeeperReserveFund;
+use near_sdk::json_types::U128;
+use near_sdk::{env, ext_contract, AccountId, Balance, Gas};
+
+const MAX_KEEPER_SCAN: usize = 50;
+const CALLBACK_ON_KEEPER_BOUNTY: Gas = Gas(10_000_000_000_000);
+
+#[ext_contract(ext_self)]
+pub trait ExtSelf {
+  fn on_transfer_keeper_bounty(&mut self, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>);
+}
+
+impl LoanFactory {
+  pub(crate) fn internal_fund_keeper_reserve(&mut self, account_id: &AccountId, amount: Balance) {
+    if amount == 0 {
+      env::panic_str("Invalid amount");
+    }
+
+    self.keeper_reserve = U128::from(self.keeper_reserve.0 + amount);
+
+    LoanKeeperReserveFund {
+      account_id,
+      amount: &U128::from(amount),
+    }.emit();
+  }
+
+  /// Up to `limit` defaulted loans with a debt, `loan_date_by_nft` is ordered by token id so the
+  /// scan continues from `keeper_cursor` and wraps around, `MAX_KEEPER_SCAN` loans per call
+  pub(crate) fn internal_scan_expired(&mut self, limit: u64) -> Vec<TokenId> {
+    let mut expired = vec![];
+    let mut last = None;
+
+    let loans: Vec<(TokenId, u64)> = match &self.keeper_cursor {
+      Some(cursor) => self.loan_date_by_nft.iter_from(cursor.clone()).take(MAX_KEEPER_SCAN).collect(),
+      None => self.loan_date_by_nft.iter().take(MAX_KEEPER_SCAN).collect(),
+    };
+    let is_end = loans.len() < MAX_KEEPER_SCAN;
+
+    for (contract_token_id, expire_date) in loans {
+      if expired.len() as u64 >= limit {
+        break;
+      }
+      if self.internal_is_loan_defaulted(&contract_token_id, expire_date)
+        && self.internal_rest_of_loan(&contract_token_id).0 > 0
+        && !self.buyout_pending_by_nft.contains_key(&contract_token_id) {
+        expired.push(contract_token_id.clone());
+      }
+
+      last = Some(contract_token_id);
+    }
+
+    self.keeper_cursor = if is_end && (expired.len() as u64) < limit { None } else { last };
+
+    expired
+  }
+
+  /// `keeper_bounty` for each liquidation, paid from `keeper_reserve` so LP fees are not used
+  pub(crate) fn internal_pay_keeper_bounty(&mut self, keeper_id: &AccountId, liquidated: u64) -> Balance {
+    let bounty = std::cmp::min(self.keeper_bounty * liquidated as u128, self.keeper_reserve.0);
+
+    if bounty == 0 {
+      return 0;
+    }
+
+    self.keeper_reserve = U128::from(self.keeper_reserve.0 - bounty);
+
+    self.internal_send(keeper_id, bounty)
+      .then(ext_self::on_transfer_keeper_bounty(
+        U128::from(bounty),
+        keeper_id.clone(),
+        self.pool_id.clone(),
+        env::current_account_id(),
+        0,
+        CALLBACK_ON_KEEPER_BOUNTY,
+      ));
+
+    bounty
+  }
+}
@@ -0,0 +1,10 @@
+use near_sdk::json_types::U128;
+
+pub trait LoanFactoryKeeper {
+  fn loan_set_keeper_bounty(&mut self, bounty: U128);
+  fn loan_keeper_liquidate(&mut self, limit: Option<u64>) -> u64;
+  fn loan_keeper_fund(&mut self);
+
+  fn loan_keeper_bounty(&self) -> U128;
+  fn loan_keeper_reserve(&self) -> U128;
+}
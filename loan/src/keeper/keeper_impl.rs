@@ -0,0 +1,66 @@
+use crate::base::LoanFactory;
+use crate::keeper::LoanFactoryKeeper;
+use crate::archive::LoanOutcome;
+use crate::event::{LoanKeeperBountyUpdate, LoanKeeperLiquidate, LoanNftClaimExpired};
+use near_sdk::json_types::U128;
+use near_sdk::env;
+
+const MAX_KEEPER_LIQUIDATIONS: u64 = 10;
+
+impl LoanFactoryKeeper for LoanFactory {
+  fn loan_set_keeper_bounty(&mut self, bounty: U128) {
+    self.assert_owner();
+
+    self.keeper_bounty = bounty.0;
+
+    LoanKeeperBountyUpdate {
+      bounty: &bounty,
+    }.emit();
+  }
+
+  fn loan_keeper_liquidate(&mut self, limit: Option<u64>) -> u64 {
+    let keeper_id = env::predecessor_account_id();
+    let limit = limit.unwrap_or(MAX_KEEPER_LIQUIDATIONS).clamp(1, MAX_KEEPER_LIQUIDATIONS);
+
+    let expired = self.internal_scan_expired(limit);
+
+    for contract_token_id in expired.iter() {
+      let arr = contract_token_id.split("||").collect::<Vec<&str>>();
+      let contract_id = near_sdk::AccountId::new_unchecked(arr[0].to_string());
+      let token_id = arr[1].to_string();
+
+      let owner_id = self.internal_default_loan(&token_id, &contract_id, LoanOutcome::Defaulted);
+
+      LoanNftClaimExpired {
+        old_owner_id: &owner_id,
+        contract_id: &contract_id,
+        token_id: &token_id,
+      }.emit();
+    }
+
+    let liquidated = expired.len() as u64;
+    let bounty = self.internal_pay_keeper_bounty(&keeper_id, liquidated);
+
+    LoanKeeperLiquidate {
+      keeper_id: &keeper_id,
+      liquidated: &liquidated,
+      bounty: &U128::from(bounty),
+    }.emit();
+
+    liquidated
+  }
+
+  fn loan_keeper_fund(&mut self) {
+    self.assert_native_currency();
+
+    self.internal_fund_keeper_reserve(&env::predecessor_account_id(), env::attached_deposit())
+  }
+
+  fn loan_keeper_bounty(&self) -> U128 {
+    U128::from(self.keeper_bounty)
+  }
+
+  fn loan_keeper_reserve(&self) -> U128 {
+    self.keeper_reserve
+  }
+}
@@ -0,0 +1,5 @@
+mod keeper_impl;
+mod keeper;
+mod internal;
+
+pub use self::keeper::LoanFactoryKeeper;
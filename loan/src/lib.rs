@@ -1,9 +1,13 @@
-use near_sdk::{AccountId, Balance, env, log, near_bindgen, PanicOnDefault, PromiseOrValue, BorshStorageKey, assert_self, is_promise_success};
+use near_sdk::{AccountId, Balance, env, log, near_bindgen, PanicOnDefault, Promise, PromiseOrValue, BorshStorageKey, IntoStorageKey, assert_self, is_promise_success};
+use near_contract_standards::non_fungible_token::{NonFungibleToken, Token};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedSet, TreeMap};
+use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet, TreeMap};
 use near_sdk::json_types::U128;use crate::base::LoanFactory;
 use std::collections::HashMap;
 use crate::utils::yton;
+use crate::archive::LoanOutcome;
+use crate::base::PayRollback;
+use crate::base::base_impl::{DEFAULT_MAINTENANCE_HEALTH, DEFAULT_MAX_APPRAISAL_MULTIPLE, DEFAULT_QUOTE_MAX_AGE, TIME_IN_DAY};
 use crate::event::{LoanFtDeposit, LoanFtWithdraw, LoanFtClaimRewards, LoanNftPay, LoanNft};
 
 mod event;
@@ -14,6 +18,30 @@ mod whitelist;
 mod storage;
 mod utils;
 mod meta;
+mod buyout;
+mod note;
+mod share;
+mod currency;
+mod pool;
+mod tranche;
+mod queue;
+mod lock;
+mod cooldown;
+mod reserve;
+mod limit;
+mod oracle;
+mod feed;
+mod quote;
+mod history;
+mod tier;
+mod appraisal;
+mod health;
+mod keeper;
+mod expiry;
+mod book;
+mod archive;
+mod credit;
+mod terms;
 
 #[derive(BorshSerialize, BorshStorageKey)]
 pub enum StorageKey {
@@ -30,12 +58,60 @@ pub enum StorageKey {
   PriceByContract,
   PercentByContract,
   SharesByAccount,
+  BuyoutPriceByNft,
+  Notes,
+  NotesApproval,
+  Pools,
+  PoolByNft,
+  Pool { pool_hash: Vec<u8>, key: Vec<u8> },
+  TrancheByAccount,
+  WithdrawQueue,
+  WithdrawQueueByAccount,
+  LocksByAccount,
+  UnbondingByAccount,
+  ExposureByContract,
+  ExposureCapByContract,
+  BorrowLimitByAccount,
+  OracleByContract,
+  PriceFeedByContract,
+  PriceKeys,
+  PriceHistoryByContract,
+  TwapWindowByContract,
+  TiersByContract,
+  TierByNft,
+  AppraisalByNft,
+  PriceManagers,
+  LoanByExpiry,
+  LoanNfts,
+  LoanIdByNft,
+  LoanRecords,
+  LoanHistoryByAccount,
+  LoanHistoryByContract,
+  LoanHistoryPerAccount { account_hash: Vec<u8> },
+  LoanHistoryPerContract { contract_hash: Vec<u8> },
+  CreditByAccount,
+  VersionedLoanRecords,
+  BuyoutPendingByNft,
+  PayoutByAccount,
+  ShareEpochByAccount,
+  NotePriceByNft,
 }
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
   loan: LoanFactory,
+  pools: UnorderedMap<PoolId, LoanFactory>,
+  pool_by_nft: LookupMap<TokenId, PoolId>,
+  next_loan_id: u64,
+}
+
+/// Default pool keeps the plain storage keys, other pools prefix them with the pool hash
+pub(crate) fn pool_storage_key(pool_id: Option<&PoolId>, key: StorageKey) -> Vec<u8> {
+  match pool_id {
+    Some(pool_id) => StorageKey::Pool { pool_hash: env::sha256(pool_id.as_bytes()), key: key.into_storage_key() }.into_storage_key(),
+    None => key.into_storage_key(),
+  }
 }
 
 #[near_bindgen]
@@ -58,23 +134,10 @@ impl Contract {
     assert!(!env::state_exists(), "Already initialized");
 
     let mut this = Self {
-      loan: LoanFactory::new(
-        owner_id,
-        9,
-        StorageKey::BalanceByAccount,
-        StorageKey::LoanBalanceByAccount,
-        StorageKey::LoanByNft,
-        StorageKey::NftContractOwner,
-        StorageKey::PriceByNft,
-        StorageKey::LoanDateByNft,
-        StorageKey::ClaimDateByAccount,
-        StorageKey::RewardByAccount,
-        StorageKey::PercentByNft,
-        StorageKey::NftByOwner,
-        StorageKey::PercentByContract,
-        StorageKey::PriceByContract,
-        StorageKey::SharesByAccount,
-      ),
+      loan: LoanFactory::new(owner_id, 9, None),
+      pools: UnorderedMap::new(StorageKey::Pools),
+      pool_by_nft: LookupMap::new(StorageKey::PoolByNft),
+      next_loan_id: 1,
     };
 
     this
@@ -119,9 +182,11 @@ impl Contract {
         }
 
         let old: Old = env::state_read().expect("Error");
+        // the deployed contract has only the default pool
+        let key = |key: StorageKey| pool_storage_key(None, key);
 
-        let loan = LoanFactory {
-          total_shares: U128::from(0),
+        let mut loan = LoanFactory {
+            total_shares: old.loan.total_shares,
             commission: old.loan.commission,
             total_rewards_pool: old.loan.total_rewards_pool,
             total_loan: old.loan.total_loan,
@@ -130,45 +195,145 @@ impl Contract {
             loan_by_nft: old.loan.loan_by_nft,
             loan_by_account: old.loan.loan_by_account,
             owner_by_nft: old.loan.owner_by_nft,
-            whitelist: old.loan.whitelist,
-            owner_id: old.loan.owner_id,
-            price_by_contract: old.loan.price_by_contract,
-            percent_by_contract: old.loan.percent_by_contract,
             price_by_nft: old.loan.price_by_nft,
             percent_by_nft: old.loan.percent_by_nft,
-            shares_by_account: LookupMap::new(StorageKey::SharesByAccount),
             loan_date_by_nft: old.loan.loan_date_by_nft,
             claim_date_by_account: old.loan.claim_date_by_account,
             reward_by_account: old.loan.reward_by_account,
             nft_by_owner: old.loan.nft_by_owner,
+            percent_by_contract: old.loan.percent_by_contract,
+            price_by_contract: old.loan.price_by_contract,
+            shares_by_account: old.loan.shares_by_account,
+            note_price_by_nft: LookupMap::new(key(StorageKey::NotePriceByNft)),
+            notes: NonFungibleToken::new(
+                key(StorageKey::Notes),
+                old.loan.owner_id.clone(),
+                None::<Vec<u8>>,
+                None::<Vec<u8>>,
+                Some(key(StorageKey::NotesApproval)),
+            ),
+            owner_id: old.loan.owner_id,
+            whitelist: old.loan.whitelist,
+            buyout_price_by_nft: LookupMap::new(key(StorageKey::BuyoutPriceByNft)),
+            buyout_pending_by_nft: LookupMap::new(key(StorageKey::BuyoutPendingByNft)),
+            payout_by_account: LookupMap::new(key(StorageKey::PayoutByAccount)),
+            total_payout: U128(0),
+            currency: None,
+            pool_id: None,
+            tranche_by_account: LookupMap::new(key(StorageKey::TrancheByAccount)),
+            senior_balance: U128::from(0),
+            senior_shares: U128::from(0),
+            senior_epoch: 0,
+            junior_epoch: 0,
+            share_epoch_by_account: LookupMap::new(key(StorageKey::ShareEpochByAccount)),
+            senior_max_apr: 8,
+            junior_fee_multiplier: 2,
+            withdraw_queue: TreeMap::new(key(StorageKey::WithdrawQueue)),
+            withdraw_queue_by_account: LookupMap::new(key(StorageKey::WithdrawQueueByAccount)),
+            withdraw_queue_next_id: 0,
+            withdraw_queue_total: U128::from(0),
+            locks_by_account: LookupMap::new(key(StorageKey::LocksByAccount)),
+            total_boost: U128::from(0),
+            senior_boost: U128::from(0),
+            unbonding_epochs: 0,
+            unbonding_by_account: LookupMap::new(key(StorageKey::UnbondingByAccount)),
+            withdraw_cap: 100,
+            withdraw_window: TIME_IN_DAY,
+            withdraw_window_start: 0,
+            withdraw_window_amount: U128::from(0),
+            reserve_ratio: 0,
+            max_utilization: 100,
+            exposure_by_contract: LookupMap::new(key(StorageKey::ExposureByContract)),
+            exposure_cap_by_contract: LookupMap::new(key(StorageKey::ExposureCapByContract)),
+            borrow_limit: BorrowLimit::default(),
+            borrow_limit_by_account: LookupMap::new(key(StorageKey::BorrowLimitByAccount)),
+            oracle_by_contract: LookupMap::new(key(StorageKey::OracleByContract)),
+            price_feed_by_contract: LookupMap::new(key(StorageKey::PriceFeedByContract)),
+            price_keys: UnorderedMap::new(key(StorageKey::PriceKeys)),
+            quote_max_age: DEFAULT_QUOTE_MAX_AGE,
+            price_history_by_contract: LookupMap::new(key(StorageKey::PriceHistoryByContract)),
+            twap_window_by_contract: LookupMap::new(key(StorageKey::TwapWindowByContract)),
+            tiers_by_contract: LookupMap::new(key(StorageKey::TiersByContract)),
+            tier_by_nft: LookupMap::new(key(StorageKey::TierByNft)),
+            appraisal_by_nft: UnorderedMap::new(key(StorageKey::AppraisalByNft)),
+            price_managers: UnorderedSet::new(key(StorageKey::PriceManagers)),
+            max_appraisal_multiple: DEFAULT_MAX_APPRAISAL_MULTIPLE,
+            maintenance_health: DEFAULT_MAINTENANCE_HEALTH,
+            liquidation_health: None,
+            keeper_bounty: 0,
+            keeper_reserve: U128(0),
+            keeper_cursor: None,
+            loan_by_expiry: TreeMap::new(key(StorageKey::LoanByExpiry)),
+            loan_nfts: UnorderedSet::new(key(StorageKey::LoanNfts)),
+            loan_id_by_nft: LookupMap::new(key(StorageKey::LoanIdByNft)),
+            loan_records: LookupMap::new(key(StorageKey::VersionedLoanRecords)),
+            loan_history_by_account: LookupMap::new(key(StorageKey::LoanHistoryByAccount)),
+            loan_history_by_contract: LookupMap::new(key(StorageKey::LoanHistoryByContract)),
+            credit_config: CreditConfig::default(),
+            credit_by_account: LookupMap::new(key(StorageKey::CreditByAccount)),
+            loan_duration: TIME_IN_WEEK,
+            grace_period: 0,
+            penalty_rate: 0,
         };
 
+        // open loans go to the expiry index, the loan book and the collection exposure,
+        // they have no archive record and keep the week terms without a grace period
+        let open_loans: Vec<(TokenId, u64)> = loan.loan_date_by_nft.iter().collect();
+
+        for (contract_token_id, expire_date) in open_loans {
+            let loan_amount = loan.loan_by_nft.get(&contract_token_id).unwrap_or(0);
+
+            loan.loan_by_expiry.insert(&(expire_date, contract_token_id.clone()), &());
+            loan.loan_nfts.insert(&contract_token_id);
+            loan.internal_increase_exposure(&contract_token_id, loan_amount);
+        }
+
+        // nfts claimed before stay in the loan book
+        if let Some(token_set) = loan.nft_by_owner.get(&env::current_account_id()) {
+            for contract_token_id in token_set.iter() {
+                loan.loan_nfts.insert(&contract_token_id);
+            }
+        }
+
         Self {
-            loan
+            loan,
+            pools: UnorderedMap::new(StorageKey::Pools),
+            pool_by_nft: LookupMap::new(StorageKey::PoolByNft),
+            next_loan_id: 1,
         }
     }
 
-  pub fn on_transfer_nft_pay(&mut self, account_id: AccountId, amount_sent: U128, fee: U128, recipient: AccountId, contract_token_id: TokenId, contract_id: AccountId, token_id: TokenId) {
+  pub fn on_transfer_nft_pay(&mut self, rollback: PayRollback) {
     assert_self();
 
     let transfer_succeeded = is_promise_success();
+    let PayRollback { account_id, contract_id, token_id, contract_token_id, principal, fee, penalty } = rollback;
+    let amount_sent = principal.0 + fee.0 + penalty.0;
 
     if transfer_succeeded {
       LoanNftPay {
         owner_id: &account_id,
         contract_id: &contract_id,
         token_id: &token_id,
-        loan_amount: &U128::from(amount_sent.0),
+        loan_amount: &U128::from(amount_sent),
       }.emit();
     }
 
     if !transfer_succeeded {
-      env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", recipient, amount_sent.0, yton(amount_sent.0)));
-
-      self.loan.internal_increase_loan_nft(&contract_token_id, &amount_sent);
-      self.loan.internal_increase_loan_balance(&account_id, &amount_sent);
-      self.loan.total_loan = U128::from(self.loan.total_loan.0 - amount_sent.0);
-      self.loan.total_rewards_pool = U128::from(self.loan.total_rewards_pool.0 - fee.0);
+      env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", env::current_account_id(), amount_sent, yton(amount_sent)));
+
+      let pool_id = self.internal_pool_id_by_nft(&contract_token_id);
+
+      // the loan is open again with its principal
+      self.internal_pool_mut(pool_id, |loan| {
+        loan.internal_increase_loan_nft(&contract_token_id, &principal);
+        loan.internal_increase_loan_balance(&account_id, &principal);
+        loan.internal_mint_note(&contract_token_id);
+        loan.internal_reopen_loan_record(&contract_token_id);
+        loan.total_loan = U128::from(loan.total_loan.0 + principal.0);
+        loan.total_rewards_pool = U128::from(loan.total_rewards_pool.0 - fee.0);
+        loan.keeper_reserve = U128::from(loan.keeper_reserve.0 - penalty.0);
+      });
     }
   }
 
@@ -191,17 +356,24 @@ impl Contract {
     if !transfer_succeeded {
       env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", recipient, amount_sent.0, yton(amount_sent.0)));
 
-      self.loan.total_loan = U128::from(self.loan.total_loan.0 - amount_sent.0);
-      self.loan.internal_decrease_loan_nft(&contract_token_id, &amount_sent);
-      self.loan.internal_decrease_loan_balance(&recipient, &amount_sent);
-      self.loan.loan_date_by_nft.remove(&contract_token_id);
-      // self.owner_by_nft.insert(&contract_token_id, &receiver_id);
-      self.loan.price_by_nft.remove(&contract_token_id);
-      self.loan.percent_by_nft.remove(&contract_token_id);
+      let pool_id = self.internal_pool_id_by_nft(&contract_token_id);
+
+      self.internal_pool_mut(pool_id, |loan| {
+        loan.total_loan = U128::from(loan.total_loan.0 - amount_sent.0);
+        loan.internal_decrease_loan_nft(&contract_token_id, &amount_sent);
+        loan.internal_decrease_loan_balance(&recipient, &amount_sent);
+        loan.internal_remove_loan_expire_date(&contract_token_id);
+        // self.owner_by_nft.insert(&contract_token_id, &receiver_id);
+        loan.price_by_nft.remove(&contract_token_id);
+        loan.percent_by_nft.remove(&contract_token_id);
+        loan.tier_by_nft.remove(&contract_token_id);
+        loan.internal_burn_note(&contract_token_id);
+        loan.internal_close_loan_record(&contract_token_id, LoanOutcome::Cancelled, 0);
+      });
     }
   }
 
-  pub fn on_transfer_loan_deposit(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId) {
+  pub fn on_transfer_loan_deposit(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>) {
     assert_self();
 
     let transfer_succeeded = is_promise_success();
@@ -215,11 +387,11 @@ impl Contract {
 
     if !transfer_succeeded {
       env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", recipient, amount_sent.0, yton(amount_sent.0)));
-      self.loan.internal_decrease_balance(&recipient, &amount_sent);
+      self.internal_pool_mut(pool_id, |loan| loan.internal_decrease_balance(&recipient, &amount_sent));
     }
   }
 
-  pub fn on_transfer_loan_withdraw(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId) {
+  pub fn on_transfer_loan_withdraw(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>) {
     assert_self();
 
     let transfer_succeeded = is_promise_success();
@@ -233,11 +405,11 @@ impl Contract {
 
     if !transfer_succeeded {
       env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", recipient, amount_sent.0, yton(amount_sent.0)));
-      self.loan.internal_increase_balance(&recipient, &amount_sent);
+      self.internal_pool_mut(pool_id, |loan| loan.internal_increase_balance(&recipient, &amount_sent));
     }
   }
 
-  pub fn on_transfer_claim_rewards(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId) {
+  pub fn on_transfer_claim_rewards(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>) {
     assert_self();
 
     let transfer_succeeded = is_promise_success();
@@ -251,8 +423,34 @@ impl Contract {
 
     if !transfer_succeeded {
       env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the app deposit", recipient, amount_sent.0, yton(amount_sent.0)));
-      self.loan.reward_by_account.insert(&recipient, &amount_sent);
-      self.loan.total_rewards_pool = U128::from(self.loan.total_rewards_pool.0 - amount_sent.0);
+      self.internal_pool_mut(pool_id, |loan| {
+        loan.reward_by_account.insert(&recipient, &amount_sent);
+        loan.total_rewards_pool = U128::from(loan.total_rewards_pool.0 - amount_sent.0);
+      });
+    }
+  }
+
+  pub fn on_transfer_payout(&mut self, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>) {
+    assert_self();
+
+    let transfer_succeeded = is_promise_success();
+
+    if !transfer_succeeded {
+      env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept for loan_payout_claim", recipient, amount_sent.0, yton(amount_sent.0)));
+      self.internal_pool_mut(pool_id, |loan| loan.internal_increase_payout(&recipient, amount_sent.0));
+    }
+  }
+
+  pub fn on_transfer_keeper_bounty(&mut self, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>) {
+    assert_self();
+
+    let transfer_succeeded = is_promise_success();
+
+    if !transfer_succeeded {
+      env::log_str(&format!("Transaction to @{} failed. {} yNEAR (~{} NEAR) kept on the keeper reserve", recipient, amount_sent.0, yton(amount_sent.0)));
+      self.internal_pool_mut(pool_id, |loan| {
+        loan.keeper_reserve = U128::from(loan.keeper_reserve.0 + amount_sent.0);
+      });
     }
   }
 }
@@ -262,3 +460,26 @@ impl Contract {
 impl_loan_core!(Contract, loan);
 impl_loan_storage!(Contract, loan);
 impl_loan_whitelist!(Contract, loan);
+impl_loan_buyout!(Contract, loan);
+impl_loan_note!(Contract, loan);
+impl_loan_share!(Contract, loan);
+impl_loan_currency!(Contract, loan);
+impl_loan_tranche!(Contract, loan);
+impl_loan_withdraw_queue!(Contract, loan);
+impl_loan_lock!(Contract, loan);
+impl_loan_cooldown!(Contract, loan);
+impl_loan_reserve!(Contract, loan);
+impl_loan_borrow_limit!(Contract, loan);
+impl_loan_oracle!(Contract, loan);
+impl_loan_price_feed!(Contract, loan);
+impl_loan_signed_price!(Contract, loan);
+impl_loan_price_history!(Contract, loan);
+impl_loan_tier!(Contract, loan);
+impl_loan_appraisal!(Contract, loan);
+impl_loan_health!(Contract, loan);
+impl_loan_keeper!(Contract, loan);
+impl_loan_expiry!(Contract, loan);
+impl_loan_book!(Contract, loan);
+impl_loan_archive!(Contract, loan);
+impl_loan_credit!(Contract, loan);
+impl_loan_terms!(Contract, loan);
@@ -0,0 +1,31 @@
+use crate::base::LoanFactory;
+use crate::limit::BorrowLimit;
+use near_sdk::{env, AccountId, Balance};
+
+impl LoanFactory {
+  /// Account override with the pool defaults for empty fields
+  pub(crate) fn internal_borrow_limit_of(&self, account_id: &AccountId) -> BorrowLimit {
+    let limit = self.borrow_limit_by_account.get(account_id).unwrap_or_default();
+
+    BorrowLimit {
+      max_principal: limit.max_principal.or(self.borrow_limit.max_principal),
+      max_loans: limit.max_loans.or(self.borrow_limit.max_loans),
+    }
+  }
+
+  pub(crate) fn internal_loans_count_of(&self, account_id: &AccountId) -> u64 {
+    self.nft_by_owner.get(account_id).map(|set| set.len()).unwrap_or(0)
+  }
+
+  pub(crate) fn assert_borrow_limit(&self, account_id: &AccountId, loan_amount: Balance) {
+    let limit = self.internal_borrow_limit_of(account_id);
+    let principal = self.internal_balance_of_loan(account_id).0 + loan_amount;
+
+    if limit.max_principal.is_some_and(|max_principal| principal > max_principal) {
+      env::panic_str("Account borrow limit is reached");
+    }
+    if limit.max_loans.is_some_and(|max_loans| self.internal_loans_count_of(account_id) >= max_loans) {
+      env::panic_str("Account open loans limit is reached");
+    }
+  }
+}
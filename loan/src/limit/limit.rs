@@ -0,0 +1,19 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::meta::JsonBorrowLimit;
+
+/// Empty fields fall back to the pool defaults
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct BorrowLimit {
+  pub max_principal: Option<Balance>,
+  pub max_loans: Option<u64>,
+}
+
+pub trait LoanFactoryBorrowLimit {
+  fn loan_set_borrow_limit(&mut self, max_principal: Option<U128>, max_loans: Option<u64>);
+  fn loan_set_account_borrow_limit(&mut self, account_id: AccountId, max_principal: Option<U128>, max_loans: Option<u64>);
+
+  fn loan_borrow_limit(&self) -> JsonBorrowLimit;
+  fn loan_borrow_limit_of(&self, account_id: AccountId) -> JsonBorrowLimit;
+}
@@ -0,0 +1,62 @@
+use crate::base::LoanFactory;
+use crate::limit::{BorrowLimit, LoanFactoryBorrowLimit};
+use crate::meta::JsonBorrowLimit;
+use crate::event::LoanBorrowLimitUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+impl LoanFactoryBorrowLimit for LoanFactory {
+  fn loan_set_borrow_limit(&mut self, max_principal: Option<U128>, max_loans: Option<u64>) {
+    self.assert_owner();
+
+    self.borrow_limit = BorrowLimit {
+      max_principal: max_principal.map(|el| el.0),
+      max_loans,
+    };
+
+    LoanBorrowLimitUpdate {
+      account_id: None,
+      max_principal: max_principal.as_ref(),
+      max_loans: max_loans.as_ref(),
+    }.emit();
+  }
+
+  fn loan_set_account_borrow_limit(&mut self, account_id: AccountId, max_principal: Option<U128>, max_loans: Option<u64>) {
+    self.assert_owner();
+
+    if max_principal.is_none() && max_loans.is_none() {
+      self.borrow_limit_by_account.remove(&account_id);
+    } else {
+      self.borrow_limit_by_account.insert(&account_id, &BorrowLimit {
+        max_principal: max_principal.map(|el| el.0),
+        max_loans,
+      });
+    }
+
+    LoanBorrowLimitUpdate {
+      account_id: Some(&account_id),
+      max_principal: max_principal.as_ref(),
+      max_loans: max_loans.as_ref(),
+    }.emit();
+  }
+
+  fn loan_borrow_limit(&self) -> JsonBorrowLimit {
+    JsonBorrowLimit {
+      max_principal: self.borrow_limit.max_principal.map(U128::from),
+      max_loans: self.borrow_limit.max_loans,
+      principal: U128::from(0),
+      loans: 0,
+    }
+  }
+
+  fn loan_borrow_limit_of(&self, account_id: AccountId) -> JsonBorrowLimit {
+    let limit = self.internal_borrow_limit_of(&account_id);
+
+    JsonBorrowLimit {
+      max_principal: limit.max_principal.map(U128::from),
+      max_loans: limit.max_loans,
+      principal: self.internal_balance_of_loan(&account_id),
+      loans: self.internal_loans_count_of(&account_id),
+    }
+  }
+}
@@ -0,0 +1,5 @@
+mod limit_impl;
+mod limit;
+mod internal;
+
+pub use self::limit::{BorrowLimit, LoanFactoryBorrowLimit};
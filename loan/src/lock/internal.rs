@@ -0,0 +1,198 @@
+use crate::base::LoanFactory;
+use crate::lock::DepositLock;
+use crate::tranche::Tranche;
+use crate::utils::date_now;
+use crate::base::base_impl::TIME_IN_DAY;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+
+/// Part of the locked amount kept by the pool on early exit, percent
+pub(crate) const EARLY_EXIT_PENALTY: u128 = 10;
+/// Locks are loaded with every balance change, so their number is bounded
+pub(crate) const MAX_ACTIVE_LOCKS: usize = 10;
+
+impl LoanFactory {
+  /// Reward multiplier in percent for the lock period
+  pub(crate) fn internal_lock_multiplier(&self, lock_days: u64) -> u64 {
+    match lock_days {
+      30 => 110,
+      90 => 125,
+      180 => 150,
+      _ => env::panic_str("Lock period should be 30, 90 or 180 days"),
+    }
+  }
+
+  fn internal_lock_boost(lock: &DepositLock) -> Balance {
+    lock.shares * (lock.multiplier - 100) as u128 / 100
+  }
+
+  pub(crate) fn internal_tranche_boost(&self, tranche: &Tranche) -> Balance {
+    match tranche {
+      Tranche::Senior => self.senior_boost.0,
+      Tranche::Junior => self.total_boost.0 - self.senior_boost.0,
+    }
+  }
+
+  pub(crate) fn internal_change_boost(&mut self, tranche: &Tranche, add: Balance, sub: Balance) {
+    self.total_boost = U128::from(self.total_boost.0 + add - sub);
+
+    if *tranche == Tranche::Senior {
+      self.senior_boost = U128::from(self.senior_boost.0 + add - sub);
+    }
+  }
+
+  /// Locks of a wiped out epoch are written off with the shares
+  pub(crate) fn internal_locks_of(&self, account_id: &AccountId) -> Vec<DepositLock> {
+    if self.internal_is_stale_shares(account_id) {
+      return vec![];
+    }
+
+    self.locks_by_account.get(account_id).unwrap_or_default()
+  }
+
+  /// Extra reward weight of the account, in shares
+  pub(crate) fn internal_boost_of(&self, account_id: &AccountId) -> Balance {
+    self.internal_locks_of(account_id)
+      .iter()
+      .map(LoanFactory::internal_lock_boost)
+      .sum()
+  }
+
+  pub(crate) fn internal_locked_shares_of(&self, account_id: &AccountId) -> Balance {
+    self.internal_locks_of(account_id)
+      .iter()
+      .map(|lock| lock.shares)
+      .sum()
+  }
+
+  pub(crate) fn internal_shares_value(&self, account_id: &AccountId, shares: Balance) -> Balance {
+    let tranche = self.internal_tranche_of(account_id);
+    let tranche_shares = self.internal_tranche_shares(&tranche);
+
+    if tranche_shares == 0 {
+      return 0;
+    }
+
+    shares * self.internal_tranche_balance(&tranche) / tranche_shares
+  }
+
+  /// Locks unlocking on the same day with the same multiplier are merged, expired locks are released before
+  pub(crate) fn internal_lock(&mut self, account_id: &AccountId, shares: Balance, lock_days: u64) {
+    let lock = DepositLock {
+      shares,
+      unlock_at: (date_now() + lock_days * TIME_IN_DAY).div_ceil(TIME_IN_DAY) * TIME_IN_DAY,
+      multiplier: self.internal_lock_multiplier(lock_days),
+    };
+    let mut locks = self.locks_by_account.get(account_id).unwrap_or_default();
+
+    // boost of the merged lock, rounding must match the boost taken on release
+    let boost = match locks.iter_mut().find(|current| current.unlock_at == lock.unlock_at && current.multiplier == lock.multiplier) {
+      Some(current) => {
+        let before = LoanFactory::internal_lock_boost(current);
+
+        current.shares += lock.shares;
+        LoanFactory::internal_lock_boost(current) - before
+      }
+      None => {
+        if locks.len() >= MAX_ACTIVE_LOCKS {
+          env::panic_str(&format!("Max {} active locks per account", MAX_ACTIVE_LOCKS));
+        }
+
+        let boost = LoanFactory::internal_lock_boost(&lock);
+
+        locks.push(lock);
+        boost
+      }
+    };
+
+    self.internal_change_boost(&self.internal_tranche_of(account_id), boost, 0);
+    self.locks_by_account.insert(account_id, &locks);
+  }
+
+  /// Drops expired locks, called after rewards of the account are checkpointed
+  pub(crate) fn internal_release_locks(&mut self, account_id: &AccountId) {
+    let locks = match self.locks_by_account.get(account_id) {
+      Some(locks) => locks,
+      None => return,
+    };
+    let now = date_now();
+    let (expired, active): (Vec<DepositLock>, Vec<DepositLock>) = locks.into_iter().partition(|lock| lock.unlock_at <= now);
+
+    if expired.is_empty() {
+      return;
+    }
+
+    let boost = expired.iter().map(LoanFactory::internal_lock_boost).sum();
+
+    self.internal_change_boost(&self.internal_tranche_of(account_id), 0, boost);
+
+    if active.is_empty() {
+      self.locks_by_account.remove(account_id);
+    } else {
+      self.locks_by_account.insert(account_id, &active);
+    }
+  }
+
+  /// Unlocks `shares` starting from the latest lock
+  fn internal_break_locks(&mut self, account_id: &AccountId, shares: Balance) {
+    let mut locks = self.locks_by_account.get(account_id).unwrap_or_default();
+    let mut rest = shares;
+    let mut boost = 0;
+
+    while rest > 0 {
+      let lock = match locks.last_mut() {
+        Some(lock) => lock,
+        None => break,
+      };
+      let unlocked = std::cmp::min(rest, lock.shares);
+      let before = LoanFactory::internal_lock_boost(lock);
+
+      lock.shares -= unlocked;
+      boost += before - LoanFactory::internal_lock_boost(lock);
+      rest -= unlocked;
+
+      if lock.shares == 0 {
+        locks.pop();
+      }
+    }
+
+    self.internal_change_boost(&self.internal_tranche_of(account_id), 0, boost);
+
+    if locks.is_empty() {
+      self.locks_by_account.remove(account_id);
+    } else {
+      self.locks_by_account.insert(account_id, &locks);
+    }
+  }
+
+  /// Decreases the balance by `amount` and returns the payout.
+  /// Locked part pays `EARLY_EXIT_PENALTY`, it stays in the tranche for the remaining LPs.
+  pub(crate) fn internal_withdraw_balance(&mut self, account_id: &AccountId, amount: &U128) -> Balance {
+    let tranche = self.internal_tranche_of(account_id);
+    let tranche_balance = self.internal_tranche_balance(&tranche);
+    let tranche_shares = self.internal_tranche_shares(&tranche);
+    let unlocked_shares = self.internal_shares_of(account_id) - self.internal_locked_shares_of(account_id);
+    let shares = (tranche_shares * amount.0).checked_div(tranche_balance).unwrap_or(0);
+
+    self.internal_use_withdraw_limit(amount.0);
+    self.internal_decrease_balance(account_id, amount);
+
+    if shares <= unlocked_shares {
+      return amount.0;
+    }
+
+    let locked_shares = shares - unlocked_shares;
+    let penalty = locked_shares * tranche_balance / tranche_shares * EARLY_EXIT_PENALTY / 100;
+
+    self.internal_break_locks(account_id, locked_shares);
+    self.internal_add_tranche(&tranche, penalty, 0);
+
+    amount.0 - penalty
+  }
+
+  pub(crate) fn assert_unlocked_shares(&self, account_id: &AccountId, shares: Balance) {
+    if shares > self.internal_shares_of(account_id) - self.internal_locked_shares_of(account_id) {
+      env::panic_str("Shares are locked");
+    }
+  }
+}
@@ -0,0 +1,17 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::meta::JsonDepositLock;
+
+/// Locked part of a deposit, kept in shares so losses of the tranche apply to it
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DepositLock {
+  pub shares: Balance,
+  pub unlock_at: u64,
+  pub multiplier: u64,
+}
+
+pub trait LoanFactoryLock {
+  fn loan_locks_of(&self, account_id: AccountId) -> Vec<JsonDepositLock>;
+  fn loan_locked_balance_of(&self, account_id: AccountId) -> U128;
+}
@@ -0,0 +1,26 @@
+use crate::base::LoanFactory;
+use crate::lock::LoanFactoryLock;
+use crate::meta::JsonDepositLock;
+use crate::utils::date_now;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+impl LoanFactoryLock for LoanFactory {
+  fn loan_locks_of(&self, account_id: AccountId) -> Vec<JsonDepositLock> {
+    let now = date_now();
+
+    self.internal_locks_of(&account_id)
+      .iter()
+      .map(|lock| JsonDepositLock {
+        amount: U128::from(self.internal_shares_value(&account_id, lock.shares)),
+        unlock_at: lock.unlock_at,
+        multiplier: lock.multiplier,
+        expired: lock.unlock_at <= now,
+      })
+      .collect()
+  }
+
+  fn loan_locked_balance_of(&self, account_id: AccountId) -> U128 {
+    U128::from(self.internal_shares_value(&account_id, self.internal_locked_shares_of(&account_id)))
+  }
+}
@@ -0,0 +1,5 @@
+mod lock_impl;
+mod lock;
+mod internal;
+
+pub use self::lock::{DepositLock, LoanFactoryLock};
@@ -4,69 +4,77 @@
 macro_rules! impl_loan_core {
     ($contract: ident, $token: ident) => {
         use $crate::base::{LoanFactoryCore, LoanFactoryResolver};
-        use $crate::base::{ContractId, TokenId};
+        use $crate::base::{ContractId, LoanRequest, PoolId, TokenId};
         use $crate::meta::{JsonLoan};
 
         #[near_bindgen]
-        impl LoanFactoryCore for $contract {
-            fn loan_balance_borrowed_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_balance_borrowed_of(account_id)
+        impl $contract {
+            pub fn loan_balance_borrowed_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_balance_borrowed_of(account_id))
             }
 
             #[payable]
-            fn loan_nft(&mut self, token_id: TokenId, contract_id: ContractId) {
-                self.$token.loan_nft(token_id, contract_id)
+            pub fn loan_nft(&mut self, token_id: TokenId, contract_id: ContractId, pool_id: Option<PoolId>, quotes: Option<Vec<SignedPriceQuote>>) {
+                self.internal_loan_nft(token_id, contract_id, pool_id, quotes.unwrap_or_default())
             }
 
-            fn loan_owner_by_id(&self, token_id: TokenId, contract_id: ContractId) -> AccountId {
-                self.$token.loan_owner_by_id(token_id, contract_id)
+            pub fn loan_owner_by_id(&self, token_id: TokenId, contract_id: ContractId) -> AccountId {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_owner_by_id(token_id, contract_id))
             }
 
-            fn loan_rest_by_id(&self, token_id: TokenId, contract_id: ContractId) -> U128 {
-                self.$token.loan_rest_by_id(token_id, contract_id)
+            pub fn loan_rest_by_id(&self, token_id: TokenId, contract_id: ContractId) -> U128 {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_rest_by_id(token_id, contract_id))
             }
 
             #[payable]
-            fn loan_nft_pay(&mut self, token_id: TokenId, contract_id: ContractId) {
-                self.$token.loan_nft_pay(token_id, contract_id)
+            pub fn loan_nft_pay(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_pay(token_id, contract_id))
             }
 
-            fn loan_nft_claim(&mut self, token_id: TokenId, contract_id: ContractId) {
-                self.$token.loan_nft_claim(token_id, contract_id)
+            pub fn loan_nft_claim(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_claim(token_id, contract_id))
             }
 
-            fn loan_update_nft_price(&mut self, contract_id: ContractId, price: U128, percent: u64) {
-                self.$token.loan_update_nft_price(contract_id, price, percent)
+            pub fn loan_update_nft_price(&mut self, contract_id: ContractId, price: U128, percent: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_update_nft_price(contract_id, price, percent))
             }
-            fn loan_nft_price(&self, contract_id: ContractId) -> Vec<U128> {
-                self.$token.loan_nft_price(contract_id)
+            pub fn loan_nft_price(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> Vec<U128> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_price(contract_id))
             }
-            fn loan_nft_claim_expired(&mut self, token_id: TokenId, contract_id: ContractId) {
-                self.$token.loan_nft_claim_expired(token_id, contract_id)
+            pub fn loan_nft_claim_expired(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_claim_expired(token_id, contract_id))
             }
 
-            fn loan_nft_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoan {
-                self.$token.loan_nft_by_id(token_id, contract_id)
+            pub fn loan_nft_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoan {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_nft_by_id(token_id, contract_id))
             }
-            fn loan_nft_by_owner(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonLoan> {
-                self.$token.loan_nft_by_owner(account_id, from_index, limit)
+            pub fn loan_nft_by_owner(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonLoan> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_by_owner(account_id, from_index, limit))
             }
 
-             fn loan_total_nft(&self) -> u128 {
-                self.$token.loan_total_nft()
+            pub fn loan_total_nft(&self, pool_id: Option<PoolId>) -> u128 {
+                self.internal_pool(pool_id, |loan| loan.loan_total_nft())
             }
-            fn loan_commission(&self) -> u128 {
-                self.$token.loan_commission()
+            pub fn loan_commission(&self, pool_id: Option<PoolId>) -> u128 {
+                self.internal_pool(pool_id, |loan| loan.loan_commission())
             }
-        }
 
-        #[near_bindgen]
-        impl LoanFactoryResolver for $contract {
-            fn loan_resolve_nft(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId, price: Balance, percent: u64) {
-                self.$token.loan_resolve_nft(receiver_id, contract_id, token_id, contract_token_id, price, percent)
+            #[private]
+            pub fn loan_resolve_nft(&mut self, request: LoanRequest) {
+                let pool_id = self.internal_pool_id_by_nft(&request.contract_token_id);
+                let loan_id = self.internal_next_loan_id();
+                self.internal_pool_mut(pool_id, |loan| loan.loan_resolve_nft(loan_id, request))
             }
-            fn loan_resolve_nft_claim(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId) {
-                self.$token.loan_resolve_nft_claim(receiver_id, contract_id, token_id, contract_token_id)
+            #[private]
+            pub fn loan_resolve_nft_claim(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId, contract_token_id: TokenId) {
+                let pool_id = self.internal_pool_id_by_nft(&contract_token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_resolve_nft_claim(receiver_id, contract_id, token_id, contract_token_id))
             }
         }
     };
@@ -78,23 +86,34 @@ macro_rules! impl_loan_core {
 macro_rules! impl_loan_whitelist {
     ($contract: ident, $token: ident) => {
         use $crate::whitelist::{LoanFactoryWhitelist};
+        use $crate::meta::{JsonExposure};
 
         #[near_bindgen]
-        impl LoanFactoryWhitelist for $contract {
+        impl $contract {
             #[payable]
-            fn loan_nft_whitelist_add(&mut self, contract_id: ContractId) {
-                self.$token.loan_nft_whitelist_add(contract_id)
+            pub fn loan_nft_whitelist_add(&mut self, contract_id: ContractId, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_whitelist_add(contract_id))
             }
             #[payable]
-            fn loan_nft_whitelist_remove(&mut self, contract_id: ContractId) {
-                self.$token.loan_nft_whitelist_remove(contract_id)
+            pub fn loan_nft_whitelist_remove(&mut self, contract_id: ContractId, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_whitelist_remove(contract_id))
+            }
+
+            pub fn loan_nft_whitelist(&self, pool_id: Option<PoolId>) -> Vec<ContractId> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_whitelist())
+            }
+            pub fn loan_nft_is_whitelist(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> bool {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_is_whitelist(contract_id))
             }
 
-            fn loan_nft_whitelist(&self) -> Vec<ContractId> {
-                self.$token.loan_nft_whitelist()
+            pub fn loan_nft_set_exposure_cap(&mut self, contract_id: ContractId, max_principal: Option<U128>, max_share: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_exposure_cap(contract_id, max_principal, max_share))
+            }
+            pub fn loan_nft_exposure(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> JsonExposure {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_exposure(contract_id))
             }
-            fn loan_nft_is_whitelist(&self, contract_id: ContractId) -> bool {
-                self.$token.loan_nft_is_whitelist(contract_id)
+            pub fn loan_nft_exposures(&self, pool_id: Option<PoolId>) -> Vec<JsonExposure> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_exposures())
             }
         }
     };
@@ -106,51 +125,750 @@ macro_rules! impl_loan_whitelist {
 macro_rules! impl_loan_storage {
     ($contract: ident, $token: ident) => {
         use $crate::storage::{LoanFactoryStorage};
+        use $crate::tranche::{Tranche};
+
+        #[near_bindgen]
+        impl $contract {
+            #[payable]
+            pub fn loan_deposit(&mut self, pool_id: Option<PoolId>, tranche: Option<Tranche>, lock_days: Option<u64>) -> U128 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_deposit(tranche, lock_days))
+            }
+            pub fn loan_withdraw(&mut self, amount: U128, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_withdraw(amount))
+            }
+            pub fn loan_withdraw_all(&mut self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_withdraw_all())
+            }
+            pub fn loan_balance_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_balance_of(account_id))
+            }
+            pub fn loan_shares_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_shares_of(account_id))
+            }
+            pub fn loan_reward_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_reward_of(account_id))
+            }
+            pub fn loan_reward_unclaimed_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_reward_unclaimed_of(account_id))
+            }
+            pub fn loan_reward_claimed_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_reward_claimed_of(account_id))
+            }
+            pub fn loan_claim_rewards(&mut self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_claim_rewards())
+            }
+            pub fn loan_total_balance(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_total_balance())
+            }
+            pub fn loan_total_shares(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_total_shares())
+            }
+            pub fn loan_total_rewards_pool(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_total_rewards_pool())
+            }
+            pub fn loan_available_balance(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_available_balance())
+            }
+            pub fn loan_total_loan(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_total_loan())
+            }
+        }
+    };
+}
+
+/// The core methods for a basic fungible token. Extension standards may be
+/// added in addition to this macro.
+#[macro_export]
+macro_rules! impl_loan_buyout {
+    ($contract: ident, $token: ident) => {
+        use $crate::buyout::{BuyoutSettlement, LoanFactoryBuyout, LoanFactoryBuyoutResolver};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_buyout_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_buyout_list(token_id, contract_id, price))
+            }
+            pub fn loan_nft_buyout_cancel(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_buyout_cancel(token_id, contract_id))
+            }
+            #[payable]
+            pub fn loan_nft_buyout(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_buyout(token_id, contract_id))
+            }
+
+            pub fn loan_nft_buyout_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128> {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_nft_buyout_price(token_id, contract_id))
+            }
+
+            #[private]
+            pub fn loan_resolve_nft_buyout(&mut self, settlement: BuyoutSettlement) {
+                let pool_id = self.internal_pool_id_by_nft(&settlement.contract_token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_resolve_nft_buyout(settlement))
+            }
+        }
+    };
+}
+
+/// Senior and junior tranches of the pool liquidity
+#[macro_export]
+macro_rules! impl_loan_tranche {
+    ($contract: ident, $token: ident) => {
+        use $crate::tranche::{LoanFactoryTranche};
+        use $crate::meta::{JsonTrancheConfig};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_tranche_config(&mut self, senior_max_apr: u64, junior_fee_multiplier: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_tranche_config(senior_max_apr, junior_fee_multiplier))
+            }
+
+            pub fn loan_tranche_config(&self, pool_id: Option<PoolId>) -> JsonTrancheConfig {
+                self.internal_pool(pool_id, |loan| loan.loan_tranche_config())
+            }
+            pub fn loan_tranche_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> Tranche {
+                self.internal_pool(pool_id, |loan| loan.loan_tranche_of(account_id))
+            }
+            pub fn loan_tranche_balance(&self, tranche: Tranche, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_tranche_balance(tranche))
+            }
+            pub fn loan_tranche_shares(&self, tranche: Tranche, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_tranche_shares(tranche))
+            }
+        }
+    };
+}
+
+/// First-in, first-out withdraw queue, paid from repayments
+#[macro_export]
+macro_rules! impl_loan_withdraw_queue {
+    ($contract: ident, $token: ident) => {
+        use $crate::queue::LoanFactoryWithdrawQueue;
+        use $crate::meta::{JsonWithdrawRequest};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_withdraw_queue(&mut self, amount: U128, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_withdraw_queue(amount))
+            }
+            pub fn loan_withdraw_queue_cancel(&mut self, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_withdraw_queue_cancel())
+            }
+
+            pub fn loan_withdraw_queue_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> Option<JsonWithdrawRequest> {
+                self.internal_pool(pool_id, |loan| loan.loan_withdraw_queue_of(account_id))
+            }
+            pub fn loan_withdraw_queue_total(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_withdraw_queue_total())
+            }
+        }
+    };
+}
+
+/// Time-locked deposits with a reward multiplier
+#[macro_export]
+macro_rules! impl_loan_lock {
+    ($contract: ident, $token: ident) => {
+        use $crate::lock::LoanFactoryLock;
+        use $crate::meta::{JsonDepositLock};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_locks_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> Vec<JsonDepositLock> {
+                self.internal_pool(pool_id, |loan| loan.loan_locks_of(account_id))
+            }
+            pub fn loan_locked_balance_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_locked_balance_of(account_id))
+            }
+        }
+    };
+}
+
+/// Unbonding period and withdraw rate limit
+#[macro_export]
+macro_rules! impl_loan_cooldown {
+    ($contract: ident, $token: ident) => {
+        use $crate::cooldown::LoanFactoryCooldown;
+        use $crate::meta::{JsonUnbonding, JsonWithdrawLimits};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_withdraw_limits(&mut self, unbonding_epochs: u64, withdraw_cap: u64, withdraw_window: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_withdraw_limits(unbonding_epochs, withdraw_cap, withdraw_window))
+            }
+            pub fn loan_withdraw_finalize(&mut self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_withdraw_finalize())
+            }
+
+            pub fn loan_withdraw_limits(&self, pool_id: Option<PoolId>) -> JsonWithdrawLimits {
+                self.internal_pool(pool_id, |loan| loan.loan_withdraw_limits())
+            }
+            pub fn loan_withdraw_limit_left(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_withdraw_limit_left())
+            }
+            pub fn loan_unbonding_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> Option<JsonUnbonding> {
+                self.internal_pool(pool_id, |loan| loan.loan_unbonding_of(account_id))
+            }
+        }
+    };
+}
+
+/// Reserve ratio and max utilization of the pool
+#[macro_export]
+macro_rules! impl_loan_reserve {
+    ($contract: ident, $token: ident) => {
+        use $crate::reserve::{LoanFactoryReserve};
+        use $crate::meta::{JsonReserve};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_reserve(&mut self, reserve_ratio: u64, max_utilization: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_reserve(reserve_ratio, max_utilization))
+            }
+
+            pub fn loan_reserve(&self, pool_id: Option<PoolId>) -> JsonReserve {
+                self.internal_pool(pool_id, |loan| loan.loan_reserve())
+            }
+            pub fn loan_lendable_balance(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_lendable_balance())
+            }
+        }
+    };
+}
+
+/// Borrow limits of an account, pool defaults and per-account overrides
+#[macro_export]
+macro_rules! impl_loan_borrow_limit {
+    ($contract: ident, $token: ident) => {
+        use $crate::limit::{LoanFactoryBorrowLimit, BorrowLimit};
+        use $crate::meta::{JsonBorrowLimit};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_borrow_limit(&mut self, max_principal: Option<U128>, max_loans: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_borrow_limit(max_principal, max_loans))
+            }
+            pub fn loan_set_account_borrow_limit(&mut self, account_id: AccountId, max_principal: Option<U128>, max_loans: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_account_borrow_limit(account_id, max_principal, max_loans))
+            }
+
+            pub fn loan_borrow_limit(&self, pool_id: Option<PoolId>) -> JsonBorrowLimit {
+                self.internal_pool(pool_id, |loan| loan.loan_borrow_limit())
+            }
+            pub fn loan_borrow_limit_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> JsonBorrowLimit {
+                self.internal_pool(pool_id, |loan| loan.loan_borrow_limit_of(account_id))
+            }
+        }
+    };
+}
+
+/// Collection prices from a cross-contract price oracle, bounded by the manual price
+#[macro_export]
+macro_rules! impl_loan_oracle {
+    ($contract: ident, $token: ident) => {
+        use $crate::oracle::{LoanFactoryOracle, LoanFactoryOracleResolver};
+        use $crate::meta::{JsonOracle};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_set_oracle(&mut self, contract_id: ContractId, oracle_id: Option<AccountId>, max_age: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_oracle(contract_id, oracle_id, max_age))
+            }
+            pub fn loan_nft_oracle(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> Option<JsonOracle> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_oracle(contract_id))
+            }
+
+            #[private]
+            pub fn loan_resolve_oracle_price(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_resolve_oracle_price(receiver_id, contract_id, token_id))
+            }
+        }
+    };
+}
+
+/// Collection prices reported by several reporters, median with a deviation guard
+#[macro_export]
+macro_rules! impl_loan_price_feed {
+    ($contract: ident, $token: ident) => {
+        use $crate::feed::LoanFactoryPriceFeed;
+        use $crate::meta::{JsonPriceFeed};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_set_reporters(&mut self, contract_id: ContractId, reporters: Vec<AccountId>, quorum: u64, max_deviation: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_reporters(contract_id, reporters, quorum, max_deviation))
+            }
+            pub fn loan_nft_report_price(&mut self, contract_id: ContractId, price: U128, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_report_price(contract_id, price))
+            }
+
+            pub fn loan_nft_price_feed(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> Option<JsonPriceFeed> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_price_feed(contract_id))
+            }
+        }
+    };
+}
+
+/// Price quotes signed off-chain by reporter keys, submitted by anyone
+#[macro_export]
+macro_rules! impl_loan_signed_price {
+    ($contract: ident, $token: ident) => {
+        use $crate::quote::{LoanFactorySignedPrice, SignedPriceQuote};
+        use $crate::meta::{JsonPriceKey};
+        use near_sdk::PublicKey;
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_add_price_key(&mut self, reporter_id: AccountId, public_key: PublicKey, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_add_price_key(reporter_id, public_key))
+            }
+            pub fn loan_nft_remove_price_key(&mut self, public_key: PublicKey, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_remove_price_key(public_key))
+            }
+            pub fn loan_set_quote_max_age(&mut self, max_age: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_quote_max_age(max_age))
+            }
+            pub fn loan_nft_submit_price(&mut self, quote: SignedPriceQuote, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_submit_price(quote))
+            }
+
+            pub fn loan_nft_price_keys(&self, pool_id: Option<PoolId>) -> Vec<JsonPriceKey> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_price_keys())
+            }
+            pub fn loan_quote_max_age(&self, pool_id: Option<PoolId>) -> u64 {
+                self.internal_pool(pool_id, |loan| loan.loan_quote_max_age())
+            }
+        }
+    };
+}
+
+/// Price history of a collection and twap for the LTV
+#[macro_export]
+macro_rules! impl_loan_price_history {
+    ($contract: ident, $token: ident) => {
+        use $crate::history::LoanFactoryPriceHistory;
+        use $crate::meta::{JsonPricePoint};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_set_twap(&mut self, contract_id: ContractId, window: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_twap(contract_id, window))
+            }
+
+            pub fn loan_nft_price_history(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonPricePoint> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_price_history(contract_id, from_index, limit))
+            }
+            pub fn loan_nft_twap(&self, contract_id: ContractId, window: Option<u64>, pool_id: Option<PoolId>) -> Option<U128> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_twap(contract_id, window))
+            }
+            pub fn loan_nft_twap_window(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> Option<u64> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_twap_window(contract_id))
+            }
+        }
+    };
+}
+
+/// Price and LTV tiers of the tokens within a collection
+#[macro_export]
+macro_rules! impl_loan_tier {
+    ($contract: ident, $token: ident) => {
+        use $crate::tier::{LoanFactoryTier, LoanFactoryTierResolver, Tier};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nft_set_tiers(&mut self, contract_id: ContractId, tiers: Vec<Tier>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_tiers(contract_id, tiers))
+            }
+
+            pub fn loan_nft_tiers(&self, contract_id: ContractId, pool_id: Option<PoolId>) -> Vec<Tier> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_tiers(contract_id))
+            }
+            pub fn loan_nft_tier_of(&self, contract_id: ContractId, token_id: TokenId, pool_id: Option<PoolId>) -> Option<Tier> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_tier_of(contract_id, token_id))
+            }
+            pub fn loan_nft_tier_by_id(&self, token_id: TokenId, contract_id: ContractId) -> Option<String> {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_nft_tier_by_id(token_id, contract_id))
+            }
+
+            #[private]
+            pub fn loan_resolve_nft_tier(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_resolve_nft_tier(receiver_id, contract_id, token_id))
+            }
+        }
+    };
+}
+
+/// Hand appraised prices of single tokens, set by the owner or price managers
+#[macro_export]
+macro_rules! impl_loan_appraisal {
+    ($contract: ident, $token: ident) => {
+        use $crate::appraisal::LoanFactoryAppraisal;
+        use $crate::meta::{JsonAppraisal};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_add_price_manager(&mut self, account_id: AccountId, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_add_price_manager(account_id))
+            }
+            pub fn loan_remove_price_manager(&mut self, account_id: AccountId, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_remove_price_manager(account_id))
+            }
+            pub fn loan_nft_set_appraisal(&mut self, contract_id: ContractId, token_id: TokenId, price: Option<U128>, percent: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_set_appraisal(contract_id, token_id, price, percent))
+            }
+            pub fn loan_set_max_appraisal_multiple(&mut self, max_multiple: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_max_appraisal_multiple(max_multiple))
+            }
+
+            pub fn loan_price_managers(&self, pool_id: Option<PoolId>) -> Vec<AccountId> {
+                self.internal_pool(pool_id, |loan| loan.loan_price_managers())
+            }
+            pub fn loan_nft_appraisal(&self, contract_id: ContractId, token_id: TokenId, pool_id: Option<PoolId>) -> Option<JsonAppraisal> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_appraisal(contract_id, token_id))
+            }
+            pub fn loan_nft_appraisals(&self, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonAppraisal> {
+                self.internal_pool(pool_id, |loan| loan.loan_nft_appraisals(from_index, limit))
+            }
+            pub fn loan_max_appraisal_multiple(&self, pool_id: Option<PoolId>) -> u64 {
+                self.internal_pool(pool_id, |loan| loan.loan_max_appraisal_multiple())
+            }
+        }
+    };
+}
+
+/// Loan health against the current collateral value, margin calls and early liquidation
+#[macro_export]
+macro_rules! impl_loan_health {
+    ($contract: ident, $token: ident) => {
+        use $crate::health::{LoanFactoryHealth};
+        use $crate::meta::{JsonHealthThresholds, JsonLoanHealth};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_health_thresholds(&mut self, maintenance_health: u64, liquidation_health: Option<u64>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_health_thresholds(maintenance_health, liquidation_health))
+            }
+            pub fn loan_margin_call(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_margin_call(token_id, contract_id))
+            }
+            pub fn loan_nft_liquidate(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_nft_liquidate(token_id, contract_id))
+            }
+
+            pub fn loan_health_thresholds(&self, pool_id: Option<PoolId>) -> JsonHealthThresholds {
+                self.internal_pool(pool_id, |loan| loan.loan_health_thresholds())
+            }
+            pub fn loan_health_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanHealth {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_health_by_id(token_id, contract_id))
+            }
+            pub fn loan_unhealthy(&self, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonLoanHealth> {
+                self.internal_pool(pool_id, |loan| loan.loan_unhealthy(from_index, limit))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_keeper {
+    ($contract: ident, $token: ident) => {
+        use $crate::keeper::{LoanFactoryKeeper};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_keeper_bounty(&mut self, bounty: U128, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_keeper_bounty(bounty))
+            }
+            pub fn loan_keeper_liquidate(&mut self, limit: Option<u64>, pool_id: Option<PoolId>) -> u64 {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_keeper_liquidate(limit))
+            }
+            #[payable]
+            pub fn loan_keeper_fund(&mut self, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_keeper_fund())
+            }
+
+            pub fn loan_keeper_bounty(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_keeper_bounty())
+            }
+            pub fn loan_keeper_reserve(&self, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_keeper_reserve())
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_expiry {
+    ($contract: ident, $token: ident) => {
+        use $crate::expiry::{LoanFactoryExpiry};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_expiring_between(&self, from: u64, to: u64, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonLoan> {
+                self.internal_pool(pool_id, |loan| loan.loan_expiring_between(from, to, from_index, limit))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_book {
+    ($contract: ident, $token: ident) => {
+        use $crate::book::{LoanFactoryBook, LoanFilter};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_nfts(&self, from_index: Option<U128>, limit: Option<u64>, filter: Option<LoanFilter>, pool_id: Option<PoolId>) -> Vec<JsonLoan> {
+                self.internal_pool(pool_id, |loan| loan.loan_nfts(from_index, limit, filter))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_archive {
+    ($contract: ident, $token: ident) => {
+        use $crate::archive::{LoanFactoryArchive};
+        use $crate::meta::{JsonLoanRecord};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_record(&self, loan_id: u64, pool_id: Option<PoolId>) -> Option<JsonLoanRecord> {
+                self.internal_pool(pool_id, |loan| loan.loan_record(loan_id))
+            }
+            pub fn loan_history_of(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonLoanRecord> {
+                self.internal_pool(pool_id, |loan| loan.loan_history_of(account_id, from_index, limit))
+            }
+            pub fn loan_history_by_contract(&self, contract_id: ContractId, from_index: Option<U128>, limit: Option<u64>, pool_id: Option<PoolId>) -> Vec<JsonLoanRecord> {
+                self.internal_pool(pool_id, |loan| loan.loan_history_by_contract(contract_id, from_index, limit))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_credit {
+    ($contract: ident, $token: ident) => {
+        use $crate::credit::{LoanFactoryCredit, CreditConfig};
+        use $crate::meta::{JsonCredit, JsonCreditConfig};
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_credit_config(&mut self, max_ltv_shift: u64, max_fee_shift: u64, full_score: u64, max_defaults: Option<u64>, min_principal: U128, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_credit_config(max_ltv_shift, max_fee_shift, full_score, max_defaults, min_principal))
+            }
+
+            pub fn loan_credit_config(&self, pool_id: Option<PoolId>) -> JsonCreditConfig {
+                self.internal_pool(pool_id, |loan| loan.loan_credit_config())
+            }
+            pub fn loan_credit_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> JsonCredit {
+                self.internal_pool(pool_id, |loan| loan.loan_credit_of(account_id))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_loan_terms {
+    ($contract: ident, $token: ident) => {
+        use $crate::terms::{LoanFactoryTerms};
+        use $crate::meta::{JsonLoanTerms};
+        use $crate::base::base_impl::TIME_IN_WEEK;
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_terms(&mut self, duration: u64, grace_period: u64, penalty_rate: u64, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_terms(duration, grace_period, penalty_rate))
+            }
+
+            pub fn loan_terms(&self, pool_id: Option<PoolId>) -> JsonLoanTerms {
+                self.internal_pool(pool_id, |loan| loan.loan_terms())
+            }
+            pub fn loan_terms_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanTerms {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_terms_by_id(token_id, contract_id))
+            }
+        }
+    };
+}
+
+/// Loan notes are NEP-171 tokens, core and approval methods are taken from near-contract-standards.
+/// Note id is `contract||token`, so it points to the pool of the loan.
+#[macro_export]
+macro_rules! impl_loan_note {
+    ($contract: ident, $token: ident) => {
+        use $crate::note::{LoanFactoryNote};
+        use near_contract_standards::non_fungible_token::core::{NonFungibleTokenCore, NonFungibleTokenResolver};
+        use near_contract_standards::non_fungible_token::approval::NonFungibleTokenApproval;
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_note_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_note_list(token_id, contract_id, price))
+            }
+            pub fn loan_note_cancel(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_note_cancel(token_id, contract_id))
+            }
+            #[payable]
+            pub fn loan_note_buy(&mut self, token_id: TokenId, contract_id: ContractId) {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.loan_note_buy(token_id, contract_id))
+            }
+
+            pub fn loan_note_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128> {
+                let pool_id = self.internal_nft_pool_id(&contract_id, &token_id);
+                self.internal_pool(pool_id, |loan| loan.loan_note_price(token_id, contract_id))
+            }
+        }
+
+        #[near_bindgen]
+        impl NonFungibleTokenCore for $contract {
+            #[payable]
+            fn nft_transfer(&mut self, receiver_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>) {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_transfer(receiver_id, token_id, approval_id, memo))
+            }
+            #[payable]
+            fn nft_transfer_call(&mut self, receiver_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>, msg: String) -> PromiseOrValue<bool> {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_transfer_call(receiver_id, token_id, approval_id, memo, msg))
+            }
+
+            fn nft_token(&self, token_id: TokenId) -> Option<Token> {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool(pool_id, |loan| loan.nft_token(token_id))
+            }
+        }
 
         #[near_bindgen]
-        impl LoanFactoryStorage for $contract {
+        impl NonFungibleTokenResolver for $contract {
+            #[private]
+            fn nft_resolve_transfer(&mut self, previous_owner_id: AccountId, receiver_id: AccountId, token_id: TokenId, approved_account_ids: Option<HashMap<AccountId, u64>>) -> bool {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_resolve_transfer(previous_owner_id, receiver_id, token_id, approved_account_ids))
+            }
+        }
+
+        #[near_bindgen]
+        impl NonFungibleTokenApproval for $contract {
             #[payable]
-            fn loan_deposit(&mut self) -> U128 {
-                self.$token.loan_deposit()
+            fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>) -> Option<Promise> {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_approve(token_id, account_id, msg))
             }
-            fn loan_withdraw(&mut self, amount: U128) -> U128 {
-                self.$token.loan_withdraw(amount)
+            #[payable]
+            fn nft_revoke(&mut self, token_id: TokenId, account_id: AccountId) {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_revoke(token_id, account_id))
             }
-            fn loan_withdraw_all(&mut self) -> U128 {
-                self.$token.loan_withdraw_all()
+            #[payable]
+            fn nft_revoke_all(&mut self, token_id: TokenId) {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool_mut(pool_id, |loan| loan.nft_revoke_all(token_id))
+            }
+
+            fn nft_is_approved(&self, token_id: TokenId, approved_account_id: AccountId, approval_id: Option<u64>) -> bool {
+                let pool_id = self.internal_pool_id_by_nft(&token_id);
+                self.internal_pool(pool_id, |loan| loan.nft_is_approved(token_id, approved_account_id, approval_id))
+            }
+        }
+    };
+}
+
+/// Shares of the default pool are exposed as a NEP-141 token, traits are taken from near-contract-standards.
+#[macro_export]
+macro_rules! impl_loan_share {
+    ($contract: ident, $token: ident) => {
+        use near_contract_standards::fungible_token::core::FungibleTokenCore;
+        use near_contract_standards::fungible_token::resolver::FungibleTokenResolver;
+        use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FungibleTokenMetadataProvider};
+
+        #[near_bindgen]
+        impl $contract {
+            /// Shares of any pool, the NEP-141 interface below is the token of the default pool
+            #[payable]
+            pub fn loan_share_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.ft_transfer(receiver_id, amount, memo))
             }
-            fn loan_balance_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_balance_of(account_id)
+        }
+
+        #[near_bindgen]
+        impl FungibleTokenCore for $contract {
+            #[payable]
+            fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>) {
+                self.$token.ft_transfer(receiver_id, amount, memo)
             }
-            fn loan_shares_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_shares_of(account_id)
+            #[payable]
+            fn ft_transfer_call(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>, msg: String) -> PromiseOrValue<U128> {
+                self.$token.ft_transfer_call(receiver_id, amount, memo, msg)
             }
-            fn loan_reward_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_reward_of(account_id)
+
+            fn ft_total_supply(&self) -> U128 {
+                self.$token.ft_total_supply()
             }
-            fn loan_reward_unclaimed_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_reward_unclaimed_of(account_id)
+            fn ft_balance_of(&self, account_id: AccountId) -> U128 {
+                self.$token.ft_balance_of(account_id)
             }
-            fn loan_reward_claimed_of(&self, account_id: AccountId) -> U128 {
-                self.$token.loan_reward_claimed_of(account_id)
+        }
+
+        #[near_bindgen]
+        impl FungibleTokenResolver for $contract {
+            #[private]
+            fn ft_resolve_transfer(&mut self, sender_id: AccountId, receiver_id: AccountId, amount: U128) -> U128 {
+                self.$token.ft_resolve_transfer(sender_id, receiver_id, amount)
             }
-             fn loan_claim_rewards(&mut self) -> U128 {
-                self.$token.loan_claim_rewards()
+        }
+
+        #[near_bindgen]
+        impl FungibleTokenMetadataProvider for $contract {
+            fn ft_metadata(&self) -> FungibleTokenMetadata {
+                self.$token.ft_metadata()
             }
-            fn loan_total_balance(&self) -> U128 {
-              self.$token.loan_total_balance()
+        }
+    };
+}
+
+/// Pool currency, deposits and repayments in NEP-141 come through `ft_on_transfer`.
+#[macro_export]
+macro_rules! impl_loan_currency {
+    ($contract: ident, $token: ident) => {
+        use $crate::currency::{LoanFactoryCurrency};
+        use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
+
+        #[near_bindgen]
+        impl $contract {
+            pub fn loan_set_currency(&mut self, currency: Option<AccountId>, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_set_currency(currency))
             }
-             fn loan_total_shares(&self) -> U128 {
-              self.$token.loan_total_shares()
+
+            pub fn loan_currency(&self, pool_id: Option<PoolId>) -> Option<AccountId> {
+                self.internal_pool(pool_id, |loan| loan.loan_currency())
             }
-            fn loan_total_rewards_pool(&self) -> U128 {
-              self.$token.loan_total_rewards_pool()
+
+            pub fn loan_payout_claim(&mut self, pool_id: Option<PoolId>) {
+                self.internal_pool_mut(pool_id, |loan| loan.loan_payout_claim())
             }
-            fn loan_available_balance(&self) -> U128 {
-              self.$token.loan_available_balance()
+
+            pub fn loan_payout_of(&self, account_id: AccountId, pool_id: Option<PoolId>) -> U128 {
+                self.internal_pool(pool_id, |loan| loan.loan_payout_of(account_id))
             }
-            fn loan_total_loan(&self) -> U128 {
-              self.$token.loan_total_loan()
+        }
+
+        #[near_bindgen]
+        impl FungibleTokenReceiver for $contract {
+            fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+                let pool_id = self.internal_pool_id_by_message(&msg);
+                self.internal_pool_mut(pool_id, |loan| loan.ft_on_transfer(sender_id, amount, msg))
             }
         }
     };
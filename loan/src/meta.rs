@@ -1,5 +1,6 @@
-use crate::base::{TokenId, ContractId};
-use near_sdk::AccountId;
+use crate::base::{TokenId, ContractId, PoolId};
+use crate::archive::LoanOutcome;
+use near_sdk::{AccountId, PublicKey};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::json_types::U128;
 
@@ -13,4 +14,196 @@ pub struct JsonLoan {
   pub expired_at: u64,
   pub price: U128,
   pub expired: bool,
+  pub note_owner_id: Option<AccountId>,
+  pub pool_id: Option<PoolId>,
+  pub tier: Option<String>,
+  pub loan_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonTrancheConfig {
+  pub senior_max_apr: u64,
+  pub junior_max_apr: u64,
+  pub junior_fee_multiplier: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonWithdrawRequest {
+  pub amount: U128,
+  pub position: u64,
+  pub amount_ahead: U128,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonDepositLock {
+  pub amount: U128,
+  pub unlock_at: u64,
+  pub multiplier: u64,
+  pub expired: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonWithdrawLimits {
+  pub unbonding_epochs: u64,
+  pub withdraw_cap: u64,
+  pub withdraw_window: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonUnbonding {
+  pub amount: U128,
+  pub epoch_height: u64,
+  pub unlock_epoch_height: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonReserve {
+  pub reserve_ratio: u64,
+  pub max_utilization: u64,
+  pub utilization: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonExposure {
+  pub contract_id: ContractId,
+  pub principal: U128,
+  pub max_principal: Option<U128>,
+  pub max_share: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonBorrowLimit {
+  pub max_principal: Option<U128>,
+  pub max_loans: Option<u64>,
+  pub principal: U128,
+  pub loans: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonOracle {
+  pub contract_id: ContractId,
+  pub oracle_id: AccountId,
+  pub max_age: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonPriceSubmission {
+  pub reporter_id: AccountId,
+  pub price: U128,
+  pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonPriceFeed {
+  pub contract_id: ContractId,
+  pub reporters: Vec<AccountId>,
+  pub quorum: u64,
+  pub max_deviation: u64,
+  pub submissions: Vec<JsonPriceSubmission>,
+  pub median: Option<U128>,
+  pub pending_price: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonPriceKey {
+  pub public_key: PublicKey,
+  pub reporter_id: AccountId,
+  pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonPricePoint {
+  pub price: U128,
+  pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonAppraisal {
+  pub token_id: TokenId,
+  pub contract_id: ContractId,
+  pub price: U128,
+  pub percent: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonHealthThresholds {
+  pub maintenance_health: u64,
+  pub liquidation_health: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonLoanHealth {
+  pub token_id: TokenId,
+  pub contract_id: ContractId,
+  pub owner_id: AccountId,
+  pub debt: U128,
+  pub value: U128,
+  pub health: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonLoanRecord {
+  pub loan_id: u64,
+  pub borrower_id: AccountId,
+  pub contract_id: ContractId,
+  pub token_id: TokenId,
+  pub price: U128,
+  pub percent: u64,
+  pub terms: JsonLoanTerms,
+  pub started_at: u64,
+  pub expire_date: u64,
+  pub repaid: U128,
+  pub closed_at: Option<u64>,
+  pub outcome: LoanOutcome,
+  pub pool_id: Option<PoolId>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonCreditConfig {
+  pub max_ltv_shift: u64,
+  pub max_fee_shift: u64,
+  pub full_score: u64,
+  pub max_defaults: Option<u64>,
+  pub min_principal: U128,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonCredit {
+  pub account_id: AccountId,
+  pub on_time: u64,
+  pub late: u64,
+  pub defaults: u64,
+  pub score: i64,
+  pub ltv_shift: i64,
+  pub commission: u128,
+  pub restricted: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonLoanTerms {
+  pub principal: U128,
+  pub commission: u128,
+  pub duration: u64,
+  pub grace_period: u64,
+  pub penalty_rate: u64,
 }
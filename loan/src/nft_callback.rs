@@ -1,7 +1,17 @@
 use crate::*;
 use near_sdk::env;
 use near_sdk::serde::{Deserialize, Serialize};
-use crate::base::TokenId;
+use crate::base::{PoolId, TokenId};
+use crate::quote::SignedPriceQuote;
+
+/// Json `msg` of `nft_on_approve`, a plain string is the pool id
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LoanNftArgs {
+    pub pool_id: Option<PoolId>,
+    #[serde(default)]
+    pub quotes: Vec<SignedPriceQuote>,
+}
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
@@ -43,7 +53,14 @@ impl NonFungibleTokenApprovalsReceiver for Contract {
             "owner_id should be signer_id"
         );
 
-      self.loan_nft(token_id, nft_contract_id);
+      // msg carries the pool id, empty msg means the default pool
+      let args = if msg.is_empty() {
+        LoanNftArgs { pool_id: None, quotes: vec![] }
+      } else {
+        near_sdk::serde_json::from_str::<LoanNftArgs>(&msg).unwrap_or(LoanNftArgs { pool_id: Some(msg), quotes: vec![] })
+      };
+
+      self.internal_loan_nft(token_id, nft_contract_id, args.pool_id, args.quotes);
         //
     }
 }
@@ -0,0 +1,29 @@
+use crate::base::{LoanFactory, TokenId};
+use near_sdk::{AccountId, env};
+
+impl LoanFactory {
+  pub(crate) fn internal_mint_note(&mut self, contract_token_id: &TokenId) {
+    self.internal_burn_note(contract_token_id);
+    self.notes.internal_mint_with_refund(contract_token_id.clone(), env::current_account_id(), None, None);
+  }
+
+  pub(crate) fn internal_burn_note(&mut self, contract_token_id: &TokenId) {
+    self.note_price_by_nft.remove(contract_token_id);
+
+    if self.notes.owner_by_id.remove(contract_token_id).is_some() {
+      if let Some(approvals_by_id) = &mut self.notes.approvals_by_id {
+        approvals_by_id.remove(contract_token_id);
+      }
+      if let Some(next_approval_id_by_id) = &mut self.notes.next_approval_id_by_id {
+        next_approval_id_by_id.remove(contract_token_id);
+      }
+    }
+  }
+
+  /// Holder of the loan note when it was sold by the pool
+  pub(crate) fn internal_note_holder(&self, contract_token_id: &TokenId) -> Option<AccountId> {
+    self.notes.owner_by_id
+      .get(contract_token_id)
+      .filter(|owner_id| owner_id != &env::current_account_id())
+  }
+}
@@ -0,0 +1,5 @@
+mod note_impl;
+mod note;
+mod internal;
+
+pub use self::note::{LoanFactoryNote};
@@ -0,0 +1,13 @@
+use near_sdk::json_types::U128;
+use crate::base::{ContractId, TokenId};
+
+/// Every funded loan mints a NEP-171 note with id `contract||token` that is held by the pool.
+/// The owner lists a note for at least the loan and its full fee, the buyer pays the listed price.
+/// Once the note is sold, repayments and the collateral of an expired loan go to its holder.
+pub trait LoanFactoryNote {
+  fn loan_note_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128);
+  fn loan_note_cancel(&mut self, token_id: TokenId, contract_id: ContractId);
+  fn loan_note_buy(&mut self, token_id: TokenId, contract_id: ContractId);
+
+  fn loan_note_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128>;
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use near_contract_standards::non_fungible_token::approval::NonFungibleTokenApproval;
+use near_contract_standards::non_fungible_token::core::{NonFungibleTokenCore, NonFungibleTokenResolver};
+use near_contract_standards::non_fungible_token::Token;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance, Promise, PromiseOrValue};
+use crate::base::{ContractId, LoanFactory, TokenId};
+use crate::event::{LoanNoteBuy, LoanNoteCancel, LoanNoteList};
+use crate::note::LoanFactoryNote;
+
+impl LoanFactoryNote for LoanFactory {
+  fn loan_note_list(&mut self, token_id: TokenId, contract_id: ContractId, price: U128) {
+    self.assert_owner();
+
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.assert_note_for_sale(&contract_token_id, price.0);
+    self.note_price_by_nft.insert(&contract_token_id, &price.0);
+
+    LoanNoteList {
+      contract_id: &contract_id,
+      token_id: &token_id,
+      price: &price,
+    }.emit();
+  }
+
+  fn loan_note_cancel(&mut self, token_id: TokenId, contract_id: ContractId) {
+    self.assert_owner();
+
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    if self.note_price_by_nft.remove(&contract_token_id).is_none() {
+      env::panic_str("Note not listed");
+    }
+
+    LoanNoteCancel {
+      contract_id: &contract_id,
+      token_id: &token_id,
+    }.emit();
+  }
+
+  fn loan_note_buy(&mut self, token_id: TokenId, contract_id: ContractId) {
+    self.assert_native_currency();
+
+    self.internal_note_buy(&env::predecessor_account_id(), token_id, contract_id, env::attached_deposit())
+  }
+
+  fn loan_note_price(&self, token_id: TokenId, contract_id: ContractId) -> Option<U128> {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    self.note_price_by_nft.get(&contract_token_id).map(U128::from)
+  }
+}
+
+impl NonFungibleTokenCore for LoanFactory {
+  fn nft_transfer(&mut self, receiver_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>) {
+    self.notes.nft_transfer(receiver_id, token_id, approval_id, memo)
+  }
+
+  fn nft_transfer_call(&mut self, receiver_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>, msg: String) -> PromiseOrValue<bool> {
+    self.notes.nft_transfer_call(receiver_id, token_id, approval_id, memo, msg)
+  }
+
+  fn nft_token(&self, token_id: TokenId) -> Option<Token> {
+    self.notes.nft_token(token_id)
+  }
+}
+
+impl NonFungibleTokenResolver for LoanFactory {
+  fn nft_resolve_transfer(&mut self, previous_owner_id: AccountId, receiver_id: AccountId, token_id: TokenId, approved_account_ids: Option<HashMap<AccountId, u64>>) -> bool {
+    self.notes.nft_resolve_transfer(previous_owner_id, receiver_id, token_id, approved_account_ids)
+  }
+}
+
+impl NonFungibleTokenApproval for LoanFactory {
+  fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>) -> Option<Promise> {
+    self.notes.nft_approve(token_id, account_id, msg)
+  }
+
+  fn nft_revoke(&mut self, token_id: TokenId, account_id: AccountId) {
+    self.notes.nft_revoke(token_id, account_id)
+  }
+
+  fn nft_revoke_all(&mut self, token_id: TokenId) {
+    self.notes.nft_revoke_all(token_id)
+  }
+
+  fn nft_is_approved(&self, token_id: TokenId, approved_account_id: AccountId, approval_id: Option<u64>) -> bool {
+    self.notes.nft_is_approved(token_id, approved_account_id, approval_id)
+  }
+}
+
+impl LoanFactory {
+  pub(crate) fn internal_note_buy(&mut self, buyer_id: &AccountId, token_id: TokenId, contract_id: ContractId, balance: Balance) {
+    let current_id = env::current_account_id();
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+    let price = self.note_price_by_nft.get(&contract_token_id).expect("Note not listed");
+
+    // the fee grows once the loan is late, so the listing is checked again
+    self.assert_note_for_sale(&contract_token_id, price);
+
+    if price != balance {
+      env::panic_str(&format!("Invalid note amount, require {}, current {}", price, balance));
+    }
+
+    let loan_amount = self.internal_rest_of_loan(&contract_token_id).0;
+
+    // the loan leaves the pool exposure before the holder changes
+    self.internal_decrease_exposure(&contract_token_id, loan_amount);
+    self.note_price_by_nft.remove(&contract_token_id);
+    self.notes.internal_transfer_unguarded(&contract_token_id, &current_id, buyer_id);
+
+    self.total_loan = U128::from(self.total_loan.0 - loan_amount);
+    self.total_rewards_pool = U128::from(self.total_rewards_pool.0 + price - loan_amount);
+    self.internal_process_withdraw_queue();
+
+    LoanNoteBuy {
+      buyer_id,
+      contract_id: &contract_id,
+      token_id: &token_id,
+      amount: &U128::from(price),
+    }.emit();
+  }
+
+  /// Note held by the pool of an open loan, `price` covers the loan with the fee of the whole term
+  fn assert_note_for_sale(&self, contract_token_id: &TokenId, price: Balance) {
+    let holder_id = self.notes.owner_by_id.get(contract_token_id).expect("Not found loan note");
+
+    if holder_id != env::current_account_id() {
+      env::panic_str("Loan note already sold");
+    }
+
+    self.assert_loan_not_expired(contract_token_id);
+    self.assert_no_pending_buyout(contract_token_id);
+
+    let loan_amount = self.internal_rest_of_loan(contract_token_id).0;
+    let min_price = loan_amount + self.internal_loan_fee(contract_token_id, loan_amount);
+
+    if price < min_price {
+      env::panic_str(&format!("Note price must cover the loan and its fee, require at least {}", min_price));
+    }
+  }
+}
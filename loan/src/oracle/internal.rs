@@ -0,0 +1,40 @@
+use crate::base::{LoanFactory, ContractId};
+use crate::oracle::PriceQuote;
+use crate::utils::{date_now, is_fresh};
+use near_sdk::{env, ext_contract, Balance, PromiseResult};
+
+#[ext_contract(ext_oracle)]
+pub trait PriceOracle {
+  fn oracle_price(&self, contract_id: ContractId) -> Option<PriceQuote>;
+}
+
+impl LoanFactory {
+  /// Fresh oracle quote of the previous promise, `None` for a failed, missing, stale or future one
+  pub(crate) fn internal_oracle_quote(&self, contract_id: &ContractId) -> Option<Balance> {
+    let oracle = self.oracle_by_contract.get(contract_id)?;
+
+    let quote = match env::promise_result(0) {
+      PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<Option<PriceQuote>>(&value).ok().flatten(),
+      _ => None,
+    }?;
+
+    if quote.price.0 == 0 || !is_fresh(quote.timestamp, date_now(), oracle.max_age) {
+      return None;
+    }
+
+    Some(quote.price.0)
+  }
+
+  /// Oracle price bounded by the manual `price_by_contract` (or its twap), the manual price is the fallback
+  pub(crate) fn internal_oracle_bounded_price(&self, contract_id: &ContractId) -> Balance {
+    let manual_price = self.internal_loan_price(contract_id);
+
+    match self.internal_oracle_quote(contract_id) {
+      Some(price) => price.min(manual_price),
+      None => {
+        env::log_str(&format!("Oracle price of {} is not available, manual price is used", contract_id));
+        manual_price
+      }
+    }
+  }
+}
@@ -0,0 +1,6 @@
+mod oracle_impl;
+mod oracle;
+mod internal;
+
+pub use self::oracle::{LoanFactoryOracle, LoanFactoryOracleResolver, OracleConfig, PriceQuote};
+pub(crate) use self::internal::ext_oracle;
@@ -0,0 +1,31 @@
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::json_types::U128;
+use crate::base::{ContractId, TokenId};
+use crate::meta::JsonOracle;
+
+/// Price feed of a collection, `max_age` in ms
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OracleConfig {
+  pub oracle_id: AccountId,
+  pub max_age: u64,
+}
+
+/// Quote returned by `oracle_price` of the oracle contract, `timestamp` in ms
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceQuote {
+  pub price: U128,
+  pub timestamp: u64,
+}
+
+pub trait LoanFactoryOracle {
+  fn loan_nft_set_oracle(&mut self, contract_id: ContractId, oracle_id: Option<AccountId>, max_age: Option<u64>);
+
+  fn loan_nft_oracle(&self, contract_id: ContractId) -> Option<JsonOracle>;
+}
+
+pub trait LoanFactoryOracleResolver {
+  fn loan_resolve_oracle_price(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId);
+}
@@ -0,0 +1,56 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::oracle::{LoanFactoryOracle, LoanFactoryOracleResolver, OracleConfig};
+use crate::meta::JsonOracle;
+use crate::event::LoanWhitelistUpdateOracle;
+use near_sdk::{env, AccountId};
+
+const DEFAULT_MAX_AGE: u64 = 600000; // 10 min
+
+impl LoanFactoryOracle for LoanFactory {
+  fn loan_nft_set_oracle(&mut self, contract_id: ContractId, oracle_id: Option<AccountId>, max_age: Option<u64>) {
+    self.assert_owner();
+
+    let max_age = max_age.unwrap_or(DEFAULT_MAX_AGE);
+
+    match &oracle_id {
+      Some(oracle_id) => {
+        if max_age == 0 {
+          env::panic_str("Invalid max age");
+        }
+
+        self.oracle_by_contract.insert(&contract_id, &OracleConfig {
+          oracle_id: oracle_id.clone(),
+          max_age,
+        });
+      }
+      None => {
+        self.oracle_by_contract.remove(&contract_id);
+      }
+    }
+
+    LoanWhitelistUpdateOracle {
+      contract_id: &contract_id,
+      oracle_id: oracle_id.as_ref(),
+      max_age: &max_age,
+    }.emit();
+  }
+
+  fn loan_nft_oracle(&self, contract_id: ContractId) -> Option<JsonOracle> {
+    self.oracle_by_contract.get(&contract_id).map(|oracle| JsonOracle {
+      contract_id,
+      oracle_id: oracle.oracle_id,
+      max_age: oracle.max_age,
+    })
+  }
+}
+
+impl LoanFactoryOracleResolver for LoanFactory {
+  fn loan_resolve_oracle_price(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId) {
+    self.assert_nft_whitelist(&contract_id);
+
+    let price = self.internal_oracle_bounded_price(&contract_id);
+    let percent = self.percent_by_contract.get(&contract_id).expect("Not found percent for current nft");
+
+    self.internal_lend_nft(receiver_id, token_id, contract_id, price, percent, None);
+  }
+}
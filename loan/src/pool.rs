@@ -0,0 +1,119 @@
+use crate::*;
+use crate::base::{ContractId, PoolId, TokenId};
+use crate::currency::LoanFtMessage;
+use crate::event::LoanPoolCreate;
+use crate::quote::{LoanFactorySignedPrice, SignedPriceQuote};
+
+#[near_bindgen]
+impl Contract {
+  /// Creates an isolated pool with its own liquidity, whitelist, prices and commission.
+  /// `owner_id` manages the whitelist and prices of the new pool.
+  pub fn loan_pool_create(&mut self, pool_id: PoolId, owner_id: AccountId, commission: u128) {
+    self.loan.assert_owner();
+
+    if pool_id.is_empty() {
+      env::panic_str("Invalid pool id");
+    }
+    if self.pools.get(&pool_id).is_some() {
+      env::panic_str("Pool already exists");
+    }
+    if commission > 100 {
+      env::panic_str("Max commission is 100");
+    }
+
+    let pool = LoanFactory::new(owner_id.clone(), commission, Some(pool_id.clone()));
+
+    self.pools.insert(&pool_id, &pool);
+
+    LoanPoolCreate {
+      pool_id: &pool_id,
+      owner_id: &owner_id,
+      commission: &commission,
+    }.emit();
+  }
+
+  pub fn loan_pools(&self) -> Vec<PoolId> {
+    self.pools.keys().collect()
+  }
+}
+
+impl Contract {
+  /// `None` is the default pool
+  pub(crate) fn internal_pool<T>(&self, pool_id: Option<PoolId>, f: impl FnOnce(&LoanFactory) -> T) -> T {
+    match pool_id {
+      Some(pool_id) => f(&self.internal_get_pool(&pool_id)),
+      None => f(&self.loan),
+    }
+  }
+
+  pub(crate) fn internal_pool_mut<T>(&mut self, pool_id: Option<PoolId>, f: impl FnOnce(&mut LoanFactory) -> T) -> T {
+    match pool_id {
+      Some(pool_id) => {
+        let mut pool = self.internal_get_pool(&pool_id);
+        let result = f(&mut pool);
+
+        self.pools.insert(&pool_id, &pool);
+
+        result
+      }
+      None => f(&mut self.loan),
+    }
+  }
+
+  /// Loan ids are shared by all pools, a failed transfer leaves a gap
+  pub(crate) fn internal_next_loan_id(&mut self) -> u64 {
+    let loan_id = self.next_loan_id;
+
+    self.next_loan_id += 1;
+
+    loan_id
+  }
+
+  pub(crate) fn internal_get_pool(&self, pool_id: &PoolId) -> LoanFactory {
+    self.pools.get(pool_id).unwrap_or_else(|| env::panic_str("Pool not found"))
+  }
+
+  pub(crate) fn internal_pool_id_by_nft(&self, contract_token_id: &TokenId) -> Option<PoolId> {
+    self.pool_by_nft.get(contract_token_id)
+  }
+
+  pub(crate) fn internal_nft_pool_id(&self, contract_id: &ContractId, token_id: &TokenId) -> Option<PoolId> {
+    self.internal_pool_id_by_nft(&self.loan.internal_get_token_id(contract_id, token_id))
+  }
+
+  pub(crate) fn internal_pool_id_by_message(&self, msg: &str) -> Option<PoolId> {
+    match near_sdk::serde_json::from_str::<LoanFtMessage>(msg) {
+      Ok(LoanFtMessage::Deposit { pool_id, .. })
+      | Ok(LoanFtMessage::KeeperFund { pool_id }) => pool_id,
+      Ok(LoanFtMessage::Pay { token_id, contract_id })
+      | Ok(LoanFtMessage::Buyout { token_id, contract_id })
+      | Ok(LoanFtMessage::NoteBuy { token_id, contract_id }) => self.internal_nft_pool_id(&contract_id, &token_id),
+      // invalid msg is rejected by the pool
+      Err(_) => None,
+    }
+  }
+
+  /// Signed `quotes` are applied to the pool before the loan, so a borrower can bring a fresh price
+  pub(crate) fn internal_loan_nft(&mut self, token_id: TokenId, contract_id: ContractId, pool_id: Option<PoolId>, quotes: Vec<SignedPriceQuote>) {
+    let contract_token_id = self.loan.internal_get_token_id(&contract_id, &token_id);
+    let current_pool_id = self.internal_pool_id_by_nft(&contract_token_id);
+
+    if current_pool_id != pool_id
+      && self.internal_pool(current_pool_id, |loan| loan.owner_by_nft.get(&contract_token_id).is_some()) {
+      env::panic_str("Nft already in loan in another pool");
+    }
+
+    match &pool_id {
+      Some(pool_id) => self.pool_by_nft.insert(&contract_token_id, pool_id),
+      None => self.pool_by_nft.remove(&contract_token_id),
+    };
+
+    self.internal_pool_mut(pool_id, |loan| {
+      for quote in quotes {
+        loan.loan_nft_submit_price(quote);
+      }
+
+      loan.loan_nft(token_id, contract_id)
+    })
+  }
+}
@@ -0,0 +1,76 @@
+use crate::base::LoanFactory;
+use crate::storage::CALLBACK_ON_WITHDRAW_QUEUE;
+use crate::storage::ext_self;
+use near_sdk::json_types::U128;
+use near_sdk::env;
+
+/// Payouts per call, the rest of the queue waits for the next repayment
+const MAX_WITHDRAW_QUEUE_PAYOUTS: usize = 3;
+
+impl LoanFactory {
+  /// Liquidity which is not lent out and not reserved for the withdraw queue
+  pub(crate) fn internal_free_balance(&self) -> U128 {
+    U128::from(self.internal_available_balance().0.saturating_sub(self.withdraw_queue_total.0))
+  }
+
+  pub(crate) fn internal_remove_withdraw_request(&mut self, request_id: &u64) {
+    let request = self.withdraw_queue.remove(request_id).expect("Not found withdraw request");
+
+    self.withdraw_queue_by_account.remove(&request.account_id);
+    self.withdraw_queue_total = U128::from(self.withdraw_queue_total.0 - request.amount);
+  }
+
+  /// Pays the queue first-in, first-out from the available liquidity
+  pub(crate) fn internal_process_withdraw_queue(&mut self) {
+    for _ in 0..MAX_WITHDRAW_QUEUE_PAYOUTS {
+      let available = std::cmp::min(self.internal_available_balance().0, self.internal_withdraw_limit_left());
+
+      if available == 0 {
+        return;
+      }
+
+      let request_id = match self.withdraw_queue.min() {
+        Some(request_id) => request_id,
+        None => return,
+      };
+      let mut request = self.withdraw_queue.get(&request_id).expect("Not found withdraw request");
+      let account_id = request.account_id.clone();
+
+      // the balance could go down after a loss
+      let amount = std::cmp::min(request.amount, self.internal_balance_of(&account_id).0);
+      let payout = std::cmp::min(amount, available);
+
+      if payout < amount {
+        request.amount -= payout;
+        self.withdraw_queue.insert(&request_id, &request);
+        self.withdraw_queue_total = U128::from(self.withdraw_queue_total.0 - payout);
+      } else {
+        self.internal_remove_withdraw_request(&request_id);
+      }
+
+      if payout == 0 {
+        continue;
+      }
+
+      self.internal_temp_claim(&account_id);
+      let sent = self.internal_withdraw_balance(&account_id, &U128::from(payout));
+
+      self.internal_send(&account_id, sent)
+        .then(
+          ext_self::on_transfer_loan_withdraw(
+            env::current_account_id(),
+            U128::from(sent),
+            account_id.clone(),
+            self.pool_id.clone(),
+            env::current_account_id(),
+            0,
+            CALLBACK_ON_WITHDRAW_QUEUE,
+          )
+        );
+
+      if payout < amount {
+        return;
+      }
+    }
+  }
+}
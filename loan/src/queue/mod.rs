@@ -0,0 +1,5 @@
+mod queue_impl;
+mod queue;
+mod internal;
+
+pub use self::queue::{LoanFactoryWithdrawQueue, WithdrawRequest};
@@ -0,0 +1,20 @@
+use near_sdk::{AccountId, Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::meta::JsonWithdrawRequest;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct WithdrawRequest {
+  pub account_id: AccountId,
+  pub amount: Balance,
+  /// Start of the unbonding the amount was taken from, kept on cancel
+  pub epoch_height: EpochHeight,
+}
+
+pub trait LoanFactoryWithdrawQueue {
+  fn loan_withdraw_queue(&mut self, amount: U128);
+  fn loan_withdraw_queue_cancel(&mut self);
+
+  fn loan_withdraw_queue_of(&self, account_id: AccountId) -> Option<JsonWithdrawRequest>;
+  fn loan_withdraw_queue_total(&self) -> U128;
+}
@@ -0,0 +1,81 @@
+use crate::base::LoanFactory;
+use crate::queue::{LoanFactoryWithdrawQueue, WithdrawRequest};
+use crate::meta::JsonWithdrawRequest;
+use crate::event::{LoanWithdrawQueue, LoanWithdrawQueueCancel};
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId};
+
+impl LoanFactoryWithdrawQueue for LoanFactory {
+  fn loan_withdraw_queue(&mut self, amount: U128) {
+    let account_id = env::predecessor_account_id();
+
+    if amount.0 == 0 {
+      env::panic_str("The amount should be a positive number");
+    }
+    if amount.0 > self.internal_balance_of(&account_id).0 {
+      env::panic_str("No funds");
+    }
+    if self.withdraw_queue_by_account.get(&account_id).is_some() {
+      env::panic_str("Already in withdraw queue");
+    }
+
+    // with unbonding period only unbonded amount can join the queue
+    let epoch_height = self.unbonding_by_account.get(&account_id).map(|el| el.epoch_height).unwrap_or_else(env::epoch_height);
+
+    self.internal_take_unbonded(&account_id, Some(amount.0));
+
+    let request_id = self.withdraw_queue_next_id;
+
+    self.withdraw_queue_next_id += 1;
+    self.withdraw_queue.insert(&request_id, &WithdrawRequest {
+      account_id: account_id.clone(),
+      amount: amount.0,
+      epoch_height,
+    });
+    self.withdraw_queue_by_account.insert(&account_id, &request_id);
+    self.withdraw_queue_total = U128::from(self.withdraw_queue_total.0 + amount.0);
+
+    LoanWithdrawQueue {
+      account_id: &account_id,
+      amount: &amount,
+    }.emit();
+
+    // free liquidity is paid right away
+    self.internal_process_withdraw_queue();
+  }
+
+  /// Cancelling keeps the unbonding progress: the unpaid amount goes back to the unbonding request
+  /// as already unbonded, queuing again puts it at the end of the queue.
+  /// The withdraw window is used only by payouts, a queued amount does not reserve it.
+  fn loan_withdraw_queue_cancel(&mut self) {
+    let account_id = env::predecessor_account_id();
+    let request_id = self.withdraw_queue_by_account.get(&account_id).expect("Not found withdraw request");
+    let request = self.withdraw_queue.get(&request_id).expect("Not found withdraw request");
+
+    self.internal_remove_withdraw_request(&request_id);
+    self.internal_restore_unbonded(&account_id, request.amount, request.epoch_height);
+
+    LoanWithdrawQueueCancel {
+      account_id: &account_id,
+    }.emit();
+  }
+
+  fn loan_withdraw_queue_of(&self, account_id: AccountId) -> Option<JsonWithdrawRequest> {
+    let request_id = self.withdraw_queue_by_account.get(&account_id)?;
+    let request = self.withdraw_queue.get(&request_id).expect("Not found withdraw request");
+    let ahead = self.withdraw_queue
+      .iter()
+      .take_while(|(id, _)| *id < request_id)
+      .fold((0u64, 0u128), |(position, amount), (_, el)| (position + 1, amount + el.amount));
+
+    Some(JsonWithdrawRequest {
+      amount: U128::from(request.amount),
+      position: ahead.0,
+      amount_ahead: U128::from(ahead.1),
+    })
+  }
+
+  fn loan_withdraw_queue_total(&self) -> U128 {
+    self.withdraw_queue_total
+  }
+}
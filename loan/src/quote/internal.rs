@@ -0,0 +1,46 @@
+use crate::base::LoanFactory;
+use crate::quote::{PriceQuotePayload, SignedPriceQuote};
+use crate::utils::{date_now, is_fresh};
+use near_sdk::borsh::BorshSerialize;
+use near_sdk::{env, AccountId};
+use ed25519_dalek::Verifier;
+use std::convert::TryFrom;
+
+impl LoanFactory {
+  /// Checks freshness, nonce and signature of the quote, returns the reporter of the key
+  pub(crate) fn internal_verify_quote(&mut self, quote: &SignedPriceQuote) -> AccountId {
+    let mut key = self.price_keys.get(&quote.public_key).expect("Not found key");
+    let now = date_now();
+
+    if !is_fresh(quote.timestamp, now, self.quote_max_age) {
+      env::panic_str("Quote is expired");
+    }
+    if quote.nonce <= key.nonce {
+      env::panic_str("Quote nonce is already used");
+    }
+
+    let payload = PriceQuotePayload {
+      loan_id: env::current_account_id(),
+      pool_id: self.pool_id.clone(),
+      contract_id: quote.contract_id.clone(),
+      price: quote.price.0,
+      timestamp: quote.timestamp,
+      nonce: quote.nonce,
+    }.try_to_vec().expect("Error");
+
+    // first byte of a near public key is the curve type
+    let public_key = ed25519_dalek::PublicKey::from_bytes(&quote.public_key.as_bytes()[1..])
+      .unwrap_or_else(|_| env::panic_str("Invalid key"));
+    let signature = ed25519_dalek::Signature::try_from(quote.signature.0.as_slice())
+      .unwrap_or_else(|_| env::panic_str("Invalid signature"));
+
+    if public_key.verify(&payload, &signature).is_err() {
+      env::panic_str("Invalid signature");
+    }
+
+    key.nonce = quote.nonce;
+    self.price_keys.insert(&quote.public_key, &key);
+
+    key.reporter_id
+  }
+}
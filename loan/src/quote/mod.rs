@@ -0,0 +1,5 @@
+mod quote_impl;
+mod quote;
+mod internal;
+
+pub use self::quote::{LoanFactorySignedPrice, PriceKey, SignedPriceQuote, PriceQuotePayload};
@@ -0,0 +1,46 @@
+use near_sdk::{AccountId, Balance, PublicKey};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::json_types::{Base64VecU8, U128};
+use crate::base::{ContractId, PoolId};
+use crate::meta::JsonPriceKey;
+
+/// Signing key of a reporter, `nonce` is the last used one
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct PriceKey {
+  pub reporter_id: AccountId,
+  pub nonce: u64,
+}
+
+/// Signed bytes are the borsh of this payload, `timestamp` in ms.
+/// `loan_id` and `pool_id` bind the quote to one pool of one loan contract
+#[derive(BorshSerialize)]
+pub struct PriceQuotePayload {
+  pub loan_id: AccountId,
+  pub pool_id: Option<PoolId>,
+  pub contract_id: ContractId,
+  pub price: Balance,
+  pub timestamp: u64,
+  pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignedPriceQuote {
+  pub contract_id: ContractId,
+  pub price: U128,
+  pub timestamp: u64,
+  pub nonce: u64,
+  pub public_key: PublicKey,
+  pub signature: Base64VecU8,
+}
+
+pub trait LoanFactorySignedPrice {
+  fn loan_nft_add_price_key(&mut self, reporter_id: AccountId, public_key: PublicKey);
+  fn loan_nft_remove_price_key(&mut self, public_key: PublicKey);
+  fn loan_set_quote_max_age(&mut self, max_age: u64);
+  fn loan_nft_submit_price(&mut self, quote: SignedPriceQuote);
+
+  fn loan_nft_price_keys(&self) -> Vec<JsonPriceKey>;
+  fn loan_quote_max_age(&self) -> u64;
+}
@@ -0,0 +1,74 @@
+use crate::base::LoanFactory;
+use crate::quote::{LoanFactorySignedPrice, PriceKey, SignedPriceQuote};
+use crate::meta::JsonPriceKey;
+use crate::event::{LoanPriceKeyUpdate, LoanQuoteMaxAgeUpdate};
+use near_sdk::{env, AccountId, CurveType, PublicKey};
+
+impl LoanFactorySignedPrice for LoanFactory {
+  fn loan_nft_add_price_key(&mut self, reporter_id: AccountId, public_key: PublicKey) {
+    self.assert_owner();
+
+    if public_key.curve_type() != CurveType::ED25519 {
+      env::panic_str("Only ed25519 keys are supported");
+    }
+    if self.price_keys.get(&public_key).is_some() {
+      env::panic_str("Key already registered");
+    }
+
+    self.price_keys.insert(&public_key, &PriceKey {
+      reporter_id: reporter_id.clone(),
+      nonce: 0,
+    });
+
+    LoanPriceKeyUpdate {
+      public_key: &public_key,
+      reporter_id: Some(&reporter_id),
+    }.emit();
+  }
+
+  fn loan_nft_remove_price_key(&mut self, public_key: PublicKey) {
+    self.assert_owner();
+
+    self.price_keys.remove(&public_key).expect("Not found key");
+
+    LoanPriceKeyUpdate {
+      public_key: &public_key,
+      reporter_id: None,
+    }.emit();
+  }
+
+  fn loan_set_quote_max_age(&mut self, max_age: u64) {
+    self.assert_owner();
+
+    if max_age < 1 {
+      env::panic_str("Invalid max age");
+    }
+
+    self.quote_max_age = max_age;
+
+    LoanQuoteMaxAgeUpdate {
+      max_age: &max_age,
+    }.emit();
+  }
+
+  fn loan_nft_submit_price(&mut self, quote: SignedPriceQuote) {
+    let reporter_id = self.internal_verify_quote(&quote);
+
+    self.internal_report_price(reporter_id, quote.contract_id, quote.price.0, quote.timestamp);
+  }
+
+  fn loan_nft_price_keys(&self) -> Vec<JsonPriceKey> {
+    self.price_keys
+      .iter()
+      .map(|(public_key, key)| JsonPriceKey {
+        public_key,
+        reporter_id: key.reporter_id,
+        nonce: key.nonce,
+      })
+      .collect()
+  }
+
+  fn loan_quote_max_age(&self) -> u64 {
+    self.quote_max_age
+  }
+}
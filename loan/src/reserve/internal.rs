@@ -0,0 +1,29 @@
+use crate::base::LoanFactory;
+use near_sdk::{env, Balance};
+
+impl LoanFactory {
+  /// `total_loan / total_balance` in percent
+  pub(crate) fn internal_utilization(&self) -> u64 {
+    if self.total_balance.0 == 0 {
+      return 0;
+    }
+
+    (self.total_loan.0 * 100 / self.total_balance.0) as u64
+  }
+
+  /// Free liquidity without the reserve, limited by the max utilization
+  pub(crate) fn internal_lendable_balance(&self) -> Balance {
+    let total_balance = self.total_balance.0;
+    let reserve = total_balance * self.reserve_ratio as u128 / 100;
+    let by_reserve = self.internal_free_balance().0.saturating_sub(reserve);
+    let by_utilization = (total_balance * self.max_utilization as u128 / 100).saturating_sub(self.total_loan.0);
+
+    std::cmp::min(by_reserve, by_utilization)
+  }
+
+  pub(crate) fn assert_lendable_balance(&self, loan_amount: Balance) {
+    if self.internal_lendable_balance() < loan_amount {
+      env::panic_str("Pool utilization limit is reached");
+    }
+  }
+}
@@ -0,0 +1,5 @@
+mod reserve_impl;
+mod reserve;
+mod internal;
+
+pub use self::reserve::{LoanFactoryReserve};
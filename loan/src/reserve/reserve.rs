@@ -0,0 +1,9 @@
+use near_sdk::json_types::U128;
+use crate::meta::JsonReserve;
+
+pub trait LoanFactoryReserve {
+  fn loan_set_reserve(&mut self, reserve_ratio: u64, max_utilization: u64);
+
+  fn loan_reserve(&self) -> JsonReserve;
+  fn loan_lendable_balance(&self) -> U128;
+}
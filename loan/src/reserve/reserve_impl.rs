@@ -0,0 +1,39 @@
+use crate::base::LoanFactory;
+use crate::reserve::LoanFactoryReserve;
+use crate::meta::JsonReserve;
+use crate::event::LoanReserveUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::env;
+
+impl LoanFactoryReserve for LoanFactory {
+  fn loan_set_reserve(&mut self, reserve_ratio: u64, max_utilization: u64) {
+    self.assert_owner();
+
+    if reserve_ratio > 100 {
+      env::panic_str("Max reserve ratio is 100");
+    }
+    if max_utilization > 100 {
+      env::panic_str("Max utilization is 100");
+    }
+
+    self.reserve_ratio = reserve_ratio;
+    self.max_utilization = max_utilization;
+
+    LoanReserveUpdate {
+      reserve_ratio: &reserve_ratio,
+      max_utilization: &max_utilization,
+    }.emit();
+  }
+
+  fn loan_reserve(&self) -> JsonReserve {
+    JsonReserve {
+      reserve_ratio: self.reserve_ratio,
+      max_utilization: self.max_utilization,
+      utilization: self.internal_utilization(),
+    }
+  }
+
+  fn loan_lendable_balance(&self) -> U128 {
+    U128::from(self.internal_lendable_balance())
+  }
+}
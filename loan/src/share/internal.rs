@@ -0,0 +1,75 @@
+use crate::base::LoanFactory;
+use near_sdk::{AccountId, Balance, env};
+use near_sdk::json_types::U128;
+use crate::event::{FtTransfer, LoanShareTransfer};
+
+impl LoanFactory {
+  pub(crate) fn internal_shares_of(&self, account_id: &AccountId) -> Balance {
+    if self.internal_is_stale_shares(account_id) {
+      return 0;
+    }
+
+    self.shares_by_account.get(account_id).unwrap_or_else(|| U128::from(0)).0
+  }
+
+  /// Moves pool shares together with the matching part of the deposited balance, both accounts stay in one tranche.
+  /// Rewards of both accounts are checkpointed first, so accrued rewards stay with the sender.
+  pub(crate) fn internal_transfer_shares(&mut self, sender_id: &AccountId, receiver_id: &AccountId, amount: Balance) {
+    if sender_id == receiver_id {
+      env::panic_str("Sender and receiver should be different");
+    }
+    if amount == 0 {
+      env::panic_str("The amount should be a positive number");
+    }
+
+    let sender_shares = self.internal_shares_of(sender_id);
+
+    if amount > sender_shares {
+      env::panic_str("The account doesn't have enough shares");
+    }
+
+    self.assert_unlocked_shares(sender_id, amount);
+
+    self.internal_sync_share_epoch(receiver_id);
+
+    let receiver_shares = self.internal_shares_of(receiver_id);
+    let tranche = self.internal_tranche_of(sender_id);
+
+    // shares of different tranches have different value
+    if receiver_shares > 0 && self.internal_tranche_of(receiver_id) != tranche {
+      env::panic_str("Receiver is in another tranche");
+    }
+
+    self.internal_temp_claim(sender_id);
+    self.internal_temp_claim(receiver_id);
+
+    let sender_deposited = self.accounts.get(sender_id).unwrap_or(0);
+    let moved_deposited = sender_deposited * amount / sender_shares;
+    let receiver_deposited = self.accounts.get(receiver_id).unwrap_or(0);
+
+    self.tranche_by_account.insert(receiver_id, &tranche);
+    self.internal_set_shares(sender_id, sender_shares - amount);
+    self.internal_set_shares(receiver_id, receiver_shares + amount);
+    self.accounts.insert(sender_id, &(sender_deposited - moved_deposited));
+    self.accounts.insert(receiver_id, &(receiver_deposited + moved_deposited));
+  }
+
+  /// NEP-141 events belong to the default pool token, shares of other pools have their own event
+  pub(crate) fn internal_emit_share_transfer(&self, old_owner_id: &AccountId, new_owner_id: &AccountId, amount: &U128, memo: Option<&str>) {
+    match &self.pool_id {
+      None => FtTransfer {
+        old_owner_id,
+        new_owner_id,
+        amount,
+        memo,
+      }.emit(),
+      Some(pool_id) => LoanShareTransfer {
+        pool_id,
+        old_owner_id,
+        new_owner_id,
+        amount,
+        memo,
+      }.emit(),
+    }
+  }
+}
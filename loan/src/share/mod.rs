@@ -0,0 +1,2 @@
+mod share_impl;
+mod internal;
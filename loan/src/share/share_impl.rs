@@ -0,0 +1,110 @@
+use near_contract_standards::fungible_token::core::FungibleTokenCore;
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FungibleTokenMetadataProvider, FT_METADATA_SPEC};
+use near_contract_standards::fungible_token::resolver::FungibleTokenResolver;
+use near_sdk::json_types::U128;
+use near_sdk::{assert_one_yocto, env, ext_contract, log, require, AccountId, Balance, Gas, PromiseOrValue, PromiseResult};
+use crate::base::LoanFactory;
+
+const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(5_000_000_000_000);
+const GAS_FOR_FT_TRANSFER_CALL: Gas = Gas(25_000_000_000_000 + GAS_FOR_RESOLVE_TRANSFER.0);
+const NO_DEPOSIT: Balance = 0;
+
+#[ext_contract(ext_self)]
+pub trait ExtSelf {
+  fn ft_resolve_transfer(&mut self, sender_id: AccountId, receiver_id: AccountId, amount: U128) -> U128;
+}
+
+#[ext_contract(ext_ft_receiver)]
+pub trait FungibleTokenReceiver {
+  fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128>;
+}
+
+impl FungibleTokenCore for LoanFactory {
+  fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>) {
+    assert_one_yocto();
+    let sender_id = env::predecessor_account_id();
+
+    self.internal_transfer_shares(&sender_id, &receiver_id, amount.0);
+
+    self.internal_emit_share_transfer(&sender_id, &receiver_id, &amount, memo.as_deref());
+  }
+
+  fn ft_transfer_call(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>, msg: String) -> PromiseOrValue<U128> {
+    assert_one_yocto();
+    require!(
+      env::prepaid_gas() > GAS_FOR_FT_TRANSFER_CALL + GAS_FOR_RESOLVE_TRANSFER,
+      "More gas is required"
+    );
+    let sender_id = env::predecessor_account_id();
+
+    self.internal_transfer_shares(&sender_id, &receiver_id, amount.0);
+
+    self.internal_emit_share_transfer(&sender_id, &receiver_id, &amount, memo.as_deref());
+
+    ext_ft_receiver::ft_on_transfer(
+      sender_id.clone(),
+      amount,
+      msg,
+      receiver_id.clone(),
+      NO_DEPOSIT,
+      env::prepaid_gas() - GAS_FOR_FT_TRANSFER_CALL,
+    ).then(ext_self::ft_resolve_transfer(
+      sender_id,
+      receiver_id,
+      amount,
+      env::current_account_id(),
+      NO_DEPOSIT,
+      GAS_FOR_RESOLVE_TRANSFER,
+    )).into()
+  }
+
+  fn ft_total_supply(&self) -> U128 {
+    self.total_shares
+  }
+
+  fn ft_balance_of(&self, account_id: AccountId) -> U128 {
+    U128::from(self.internal_shares_of(&account_id))
+  }
+}
+
+impl FungibleTokenResolver for LoanFactory {
+  fn ft_resolve_transfer(&mut self, sender_id: AccountId, receiver_id: AccountId, amount: U128) -> U128 {
+    let unused_amount = match env::promise_result(0) {
+      PromiseResult::NotReady => env::abort(),
+      PromiseResult::Successful(value) => {
+        if let Ok(unused_amount) = near_sdk::serde_json::from_slice::<U128>(&value) {
+          std::cmp::min(amount.0, unused_amount.0)
+        } else {
+          amount.0
+        }
+      }
+      PromiseResult::Failed => amount.0,
+    };
+
+    let refund_amount = std::cmp::min(self.internal_shares_of(&receiver_id), unused_amount);
+
+    if refund_amount > 0 {
+      self.internal_transfer_shares(&receiver_id, &sender_id, refund_amount);
+
+      log!("Refund {} from {} to {}", refund_amount, receiver_id, sender_id);
+
+      self.internal_emit_share_transfer(&receiver_id, &sender_id, &U128::from(refund_amount), Some("refund"));
+    }
+
+    U128::from(amount.0 - refund_amount)
+  }
+}
+
+impl FungibleTokenMetadataProvider for LoanFactory {
+  fn ft_metadata(&self) -> FungibleTokenMetadata {
+    FungibleTokenMetadata {
+      spec: FT_METADATA_SPEC.to_string(),
+      name: "Loan pool share".to_string(),
+      symbol: "LOAN-LP".to_string(),
+      icon: None,
+      reference: None,
+      reference_hash: None,
+      decimals: 24,
+    }
+  }
+}
@@ -5,7 +5,7 @@ use near_sdk::json_types::U128;
 
 impl LoanFactory {
   pub(crate) fn assert_available_balance(&self, amount: &U128) {
-    let available_balance = self.internal_available_balance();
+    let available_balance = self.internal_free_balance();
 
     if available_balance.0 < amount.0 {
       env::panic_str(&"Not found available fund");
@@ -21,43 +21,65 @@ impl LoanFactory {
   }
 
   // storage
+  /// Value of account shares in its tranche, so written off losses are taken into account
   pub(crate) fn internal_balance_of(&self, account_id: &AccountId) -> U128 {
-      U128::from(self.accounts.get(&account_id).unwrap_or_else(|| 0))
+    let tranche = self.internal_tranche_of(account_id);
+    let tranche_shares = self.internal_tranche_shares(&tranche);
+
+    if tranche_shares == 0 {
+      return U128::from(0);
+    }
+
+    U128::from(self.internal_shares_of(account_id) * self.internal_tranche_balance(&tranche) / tranche_shares)
   }
   pub(crate) fn internal_increase_balance(&mut self, account_id: &AccountId, amount: &U128) {
+    let tranche = self.internal_tranche_of(account_id);
+
+    if self.internal_tranche_balance(&tranche) == 0 && self.internal_tranche_shares(&tranche) > 0 {
+      self.internal_rebase_tranche(&tranche);
+    }
+
+    self.internal_sync_share_epoch(account_id);
+
+    let tranche_balance = self.internal_tranche_balance(&tranche);
+    let tranche_shares = self.internal_tranche_shares(&tranche);
     let current = self.accounts.get(&account_id).unwrap_or_else(|| 0);
     let next = current + amount.0;
-    let num_shares = if self.total_balance.0 == 0 {
-      U128::from(100)
+
+    let num_shares = if tranche_balance == 0 {
+      U128::from(amount.0)
     } else {
-      U128::from(self.total_shares.0 * amount.0 / self.total_balance.0)
+      U128::from(tranche_shares * amount.0 / tranche_balance)
     };
-    let current_shares = self.shares_by_account.get(&account_id).unwrap_or_else(|| U128::from(0));
-    let new_shares = U128::from(num_shares.0 + current_shares.0);
+    let new_shares = num_shares.0 + self.internal_shares_of(account_id);
 
     self.accounts.insert(&account_id, &next);
-    self.total_balance = U128::from(self.total_balance.0 + amount.0);
-
-    self.total_shares = U128::from(self.total_shares.0 + num_shares.0);
-    self.shares_by_account.insert(&account_id, &new_shares);
+    self.internal_add_tranche(&tranche, amount.0, num_shares.0);
+    self.internal_set_shares(account_id, new_shares);
   }
   pub(crate) fn internal_decrease_balance(&mut self, account_id: &AccountId, amount: &U128) {
-    let current = self.accounts.get(&account_id).unwrap_or_else(|| 0);
-    let num_shares = U128::from(self.total_shares.0 * amount.0 / self.total_balance.0);
-    let current_shares = self.shares_by_account.get(&account_id).unwrap_or_else(|| U128::from(0));
-    let new_shares = U128::from(num_shares.0 - current_shares.0);
+    let tranche = self.internal_tranche_of(account_id);
+    let tranche_balance = self.internal_tranche_balance(&tranche);
+    let tranche_shares = self.internal_tranche_shares(&tranche);
+    let current = self.internal_balance_of(account_id).0;
 
     if amount.0 > current {
         env::panic_str("No funds");
     }
 
-    let next = current - amount.0;
-
-    self.accounts.insert(&account_id, &next);
-    self.total_balance = U128::from(self.total_balance.0 - amount.0);
+    // rounded up, so the rounding dust stays with the pool
+    let num_shares = if amount.0 == 0 {
+      U128::from(0)
+    } else {
+      U128::from((tranche_shares * amount.0).div_ceil(tranche_balance))
+    };
+    let new_shares = self.internal_shares_of(account_id) - num_shares.0;
+    // deposited amount, kept for history
+    let deposited = self.accounts.get(&account_id).unwrap_or_else(|| 0);
 
-    self.total_shares = U128::from(self.total_shares.0 - num_shares.0);
-    self.shares_by_account.insert(&account_id, &new_shares);
+    self.accounts.insert(&account_id, &deposited.saturating_sub(amount.0));
+    self.internal_sub_tranche(&tranche, amount.0, num_shares.0);
+    self.internal_set_shares(account_id, new_shares);
   }
   pub(crate) fn internal_reward_claimed_of(&self, account_id: &AccountId) -> U128 {
       self.reward_by_account.get(&account_id).unwrap_or_else(|| U128::from(0))
@@ -77,11 +99,15 @@ impl LoanFactory {
             return U128::from(0);
           }
 
-          let total_shares = self.total_shares.0;
-          let total_rewards = self.total_rewards_pool.0;
-          let rewards = total_rewards * num_shares.0 / total_shares;
+          // locked deposits have a bigger weight
+          let tranche = self.internal_tranche_of(&account_id);
+          let weighted_shares = num_shares.0 + self.internal_boost_of(&account_id);
+          let tranche_weighted_shares = self.internal_tranche_shares(&tranche) + self.internal_tranche_boost(&tranche);
+          let tranche_rewards = self.internal_tranche_rewards(&tranche);
+          let rewards = tranche_rewards * weighted_shares / tranche_weighted_shares;
 
-          let max_rewards = balance.0 * 28 / 100 / 31536000000000000 * time_diff;
+          let max_apr = self.internal_tranche_max_apr(&tranche) as u128;
+          let max_rewards = balance.0 * max_apr / 100 / 31536000000000000 * time_diff * weighted_shares / num_shares.0;
 
           if rewards > max_rewards {
             return U128::from(max_rewards);
@@ -102,6 +128,7 @@ impl LoanFactory {
       let next_rewards = U128::from(prev_rewards.0 + add_rewards.0);
 
       self.reward_by_account.insert(&account_id,&next_rewards);
+      self.internal_release_locks(account_id);
   }
   pub(crate) fn internal_available_balance(&self) -> U128 {
     U128::from(self.total_balance.0 - self.total_loan.0)
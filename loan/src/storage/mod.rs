@@ -3,3 +3,4 @@ mod storage_impl;
 mod internal;
 
 pub use self::storage::{LoanFactoryStorage};
+pub(crate) use self::storage_impl::{ext_self, CALLBACK_ON_WITHDRAW_QUEUE};
@@ -1,8 +1,9 @@
 use near_sdk::json_types::U128;
 use near_sdk::AccountId;
+use crate::tranche::Tranche;
 
 pub trait LoanFactoryStorage {
-  fn loan_deposit(&mut self) -> U128;
+  fn loan_deposit(&mut self, tranche: Option<Tranche>, lock_days: Option<u64>) -> U128;
   fn loan_withdraw(&mut self, amount: U128) -> U128;
   fn loan_withdraw_all(&mut self) -> U128;
   fn loan_claim_rewards(&mut self) -> U128;
@@ -1,27 +1,33 @@
-use crate::base::LoanFactory;
+use crate::base::{LoanFactory, PoolId};
 use crate::storage::LoanFactoryStorage;
 use near_sdk::json_types::U128;
 use near_sdk::{AccountId, env, Promise, Gas, ext_contract};
 use crate::utils::method_disabled;
 use crate::event::{LoanFtDeposit, LoanFtWithdraw, LoanFtClaimRewards};
+use crate::tranche::Tranche;
 
 pub const CALLBACK_ON_DEPOSIT: Gas = Gas(50_000_000_000_000);
+pub const CALLBACK_ON_WITHDRAW_QUEUE: Gas = Gas(10_000_000_000_000);
 
 #[ext_contract(ext_self)]
 pub trait ExtSelf {
-  fn on_transfer_loan_deposit(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId);
-  fn on_transfer_loan_withdraw(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId);
-  fn on_transfer_claim_rewards(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId);
+  fn on_transfer_loan_deposit(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>);
+  fn on_transfer_loan_withdraw(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>);
+  fn on_transfer_claim_rewards(&mut self, account_id: AccountId, amount_sent: U128, recipient: AccountId, pool_id: Option<PoolId>);
 }
 
 impl LoanFactoryStorage for LoanFactory {
-    fn loan_deposit(&mut self) -> U128 {
+    fn loan_deposit(&mut self, tranche: Option<Tranche>, lock_days: Option<u64>) -> U128 {
         let account_id = env::predecessor_account_id();
         let balance = env::attached_deposit();
 
+        self.assert_native_currency();
+
         self.internal_temp_claim(&account_id);
+        self.internal_set_tranche(&account_id, tranche);
 
-        self.internal_increase_balance(&account_id, &U128::from(balance));
+        self.internal_increase_locked_balance(&account_id, &U128::from(balance), lock_days);
+        self.internal_process_withdraw_queue();
 
         Promise::new(env::current_account_id())
           .transfer(balance)
@@ -30,6 +36,7 @@ impl LoanFactoryStorage for LoanFactory {
               env::predecessor_account_id(),
               U128::from(balance),
               env::current_account_id(),
+              self.pool_id.clone(),
               env::current_account_id(),
               0,
               CALLBACK_ON_DEPOSIT,
@@ -46,31 +53,13 @@ impl LoanFactoryStorage for LoanFactory {
     fn loan_withdraw(&mut self, amount: U128) -> U128 {
       let account_id = env::predecessor_account_id();
 
-      self.assert_available_balance(&amount);
-
-      self.internal_temp_claim(&account_id);
-      // self.loan_claim_rewards();
-
-      self.internal_decrease_balance(&account_id, &amount);
-      Promise::new(account_id.clone())
-        .transfer(amount.0)
-        .then(
-          ext_self::on_transfer_loan_withdraw(
-            env::current_account_id(),
-            amount.clone(),
-            account_id.clone(),
-            env::current_account_id(),
-            0,
-            CALLBACK_ON_DEPOSIT,
-          )
-        );
-
-      // LoanFtWithdraw {
-      //   account_id: &account_id,
-      //   amount: &amount,
-      // }.emit();
-
-      amount
+      if self.unbonding_epochs > 0 {
+        self.internal_unbond(&account_id, amount);
+
+        return amount;
+      }
+
+      self.internal_withdraw(&account_id, amount)
     }
     fn loan_withdraw_all(&mut self) -> U128 {
       let account_id = env::predecessor_account_id();
@@ -90,13 +79,13 @@ impl LoanFactoryStorage for LoanFactory {
     self.reward_by_account.insert(&account_id, &U128::from(0));
     self.total_rewards_pool = U128::from(self.total_rewards_pool.0 - amount.0);
 
-    Promise::new(account_id.clone())
-      .transfer(amount.0)
+    self.internal_send(&account_id, amount.0)
       .then(
       ext_self::on_transfer_claim_rewards(
         env::current_account_id(),
         amount.clone(),
         account_id.clone(),
+        self.pool_id.clone(),
         env::current_account_id(),
         0,
         CALLBACK_ON_DEPOSIT,
@@ -143,7 +132,7 @@ impl LoanFactoryStorage for LoanFactory {
   }
 
   fn loan_shares_of(&self, account_id: AccountId) -> U128 {
-    self.shares_by_account.get(&account_id).unwrap_or_else(|| U128::from(0))
+    U128::from(self.internal_shares_of(&account_id))
   }
 
   fn loan_reward_claimed_of(&self, account_id: AccountId) -> U128 {
@@ -154,3 +143,59 @@ impl LoanFactoryStorage for LoanFactory {
      self.internal_reward_unclaimed_of(&account_id)
   }
 }
+
+impl LoanFactory {
+  pub(crate) fn internal_deposit(&mut self, account_id: &AccountId, amount: U128, tranche: Option<Tranche>, lock_days: Option<u64>) {
+    self.internal_temp_claim(account_id);
+    self.internal_set_tranche(account_id, tranche);
+    self.internal_increase_locked_balance(account_id, &amount, lock_days);
+    self.internal_process_withdraw_queue();
+
+    LoanFtDeposit {
+      account_id,
+      amount: &amount,
+    }.emit();
+  }
+
+  /// Deposit, the new shares are locked when `lock_days` is set
+  pub(crate) fn internal_increase_locked_balance(&mut self, account_id: &AccountId, amount: &U128, lock_days: Option<u64>) {
+    let shares_before = self.internal_shares_of(account_id);
+
+    self.internal_increase_balance(account_id, amount);
+
+    if let Some(lock_days) = lock_days {
+      self.internal_lock(account_id, self.internal_shares_of(account_id) - shares_before, lock_days);
+    }
+  }
+
+  pub(crate) fn internal_withdraw(&mut self, account_id: &AccountId, amount: U128) -> U128 {
+    if self.internal_free_balance().0 < amount.0 {
+      env::panic_str("Not found available fund, use loan_withdraw_queue");
+    }
+
+    self.internal_temp_claim(account_id);
+    // self.loan_claim_rewards();
+
+    let payout = U128::from(self.internal_withdraw_balance(account_id, &amount));
+
+    self.internal_send(account_id, payout.0)
+      .then(
+        ext_self::on_transfer_loan_withdraw(
+          env::current_account_id(),
+          payout.clone(),
+          account_id.clone(),
+          self.pool_id.clone(),
+          env::current_account_id(),
+          0,
+          CALLBACK_ON_DEPOSIT,
+        )
+      );
+
+    // LoanFtWithdraw {
+    //   account_id: &account_id,
+    //   amount: &amount,
+    // }.emit();
+
+    amount
+  }
+}
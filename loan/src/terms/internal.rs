@@ -0,0 +1,106 @@
+use crate::base::{LoanFactory, TokenId};
+use crate::base::base_impl::TIME_IN_WEEK;
+use crate::terms::LoanTerms;
+use crate::meta::JsonLoanTerms;
+use crate::utils::date_now;
+use near_sdk::json_types::U128;
+use near_sdk::{env, Balance};
+
+impl LoanFactory {
+  pub(crate) fn internal_new_loan_terms(&self, principal: Balance, commission: u128) -> LoanTerms {
+    LoanTerms {
+      principal,
+      commission,
+      duration: self.loan_duration,
+      grace_period: self.grace_period,
+      penalty_rate: self.penalty_rate,
+    }
+  }
+
+  /// Loans taken before the terms were archived keep the week term without a grace period
+  pub(crate) fn internal_loan_terms(&self, contract_token_id: &TokenId) -> LoanTerms {
+    match self.loan_id_by_nft.get(contract_token_id).and_then(|loan_id| self.internal_loan_record(loan_id)) {
+      Some(record) => record.terms,
+      None => LoanTerms {
+        principal: self.internal_rest_of_loan(contract_token_id).0,
+        commission: self.commission,
+        duration: TIME_IN_WEEK,
+        grace_period: 0,
+        penalty_rate: 0,
+      },
+    }
+  }
+
+  /// After the grace period the collateral can be taken
+  pub(crate) fn internal_is_loan_defaulted(&self, contract_token_id: &TokenId, expire_date: u64) -> bool {
+    expire_date + self.internal_loan_terms(contract_token_id).grace_period < date_now()
+  }
+
+  pub(crate) fn assert_loan_defaulted(&self, contract_token_id: &TokenId) {
+    let expire_date = self.loan_date_by_nft.get(contract_token_id).expect("Not found loan expire date");
+
+    if !self.internal_is_loan_defaulted(contract_token_id, expire_date) {
+      env::panic_str("Loan is in the grace period");
+    }
+  }
+
+  pub(crate) fn assert_loan_not_defaulted(&self, contract_token_id: &TokenId) {
+    let expire_date = self.loan_date_by_nft.get(contract_token_id).expect("Not found loan expire date");
+
+    if self.internal_is_loan_defaulted(contract_token_id, expire_date) {
+      env::panic_str("Loan is expired");
+    }
+  }
+
+  fn internal_is_loan_late(&self, contract_token_id: &TokenId) -> bool {
+    self.loan_date_by_nft.get(contract_token_id).is_some_and(|expire_date| expire_date < date_now())
+  }
+
+  /// Fee and the late penalty of the loan terms
+  pub(crate) fn internal_loan_fee(&self, contract_token_id: &TokenId, loan_amount: Balance) -> Balance {
+    self.internal_loan_terms(contract_token_id).fee(loan_amount, self.internal_is_loan_late(contract_token_id))
+  }
+
+  /// Late penalty part of `internal_loan_fee`
+  pub(crate) fn internal_loan_penalty(&self, contract_token_id: &TokenId, loan_amount: Balance) -> Balance {
+    let terms = self.internal_loan_terms(contract_token_id);
+
+    terms.fee(loan_amount, self.internal_is_loan_late(contract_token_id)) - terms.fee(loan_amount, false)
+  }
+
+  pub(crate) fn enum_get_loan_terms(&self, terms: &LoanTerms) -> JsonLoanTerms {
+    JsonLoanTerms {
+      principal: U128::from(terms.principal),
+      commission: terms.commission,
+      duration: terms.duration,
+      grace_period: terms.grace_period,
+      penalty_rate: terms.penalty_rate,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::terms::LoanTerms;
+
+  fn terms(commission: u128, penalty_rate: u64) -> LoanTerms {
+    LoanTerms { principal: 1000, commission, duration: 0, grace_period: 0, penalty_rate }
+  }
+
+  #[test]
+  fn fee_on_time_is_commission_only() {
+    assert_eq!(terms(10, 5).fee(1000, false), 100);
+  }
+
+  #[test]
+  fn fee_late_adds_penalty() {
+    assert_eq!(terms(10, 5).fee(1000, true), 150);
+    assert_eq!(terms(10, 0).fee(1000, true), 100);
+  }
+
+  #[test]
+  fn fee_rounds_down() {
+    assert_eq!(terms(3, 3).fee(99, true), 4);
+    assert_eq!(terms(10, 5).fee(0, true), 0);
+  }
+}
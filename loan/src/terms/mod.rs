@@ -0,0 +1,5 @@
+mod terms_impl;
+mod terms;
+mod internal;
+
+pub use self::terms::{LoanFactoryTerms, LoanTerms};
@@ -0,0 +1,35 @@
+use near_sdk::Balance;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use crate::base::{ContractId, TokenId};
+use crate::meta::JsonLoanTerms;
+
+/// Fixed when the loan is taken, later pool changes do not touch open loans.
+/// A loan can be paid during `grace_period` after the expire date with `penalty_rate` percent on top of the fee
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LoanTerms {
+  pub principal: Balance,
+  pub commission: u128,
+  pub duration: u64,
+  pub grace_period: u64,
+  pub penalty_rate: u64,
+}
+
+impl LoanTerms {
+  /// Commission fee of `loan_amount`, a late loan adds the penalty
+  pub fn fee(&self, loan_amount: Balance, is_late: bool) -> Balance {
+    let fee = loan_amount * self.commission / 100;
+
+    if is_late {
+      fee + loan_amount * self.penalty_rate as u128 / 100
+    } else {
+      fee
+    }
+  }
+}
+
+pub trait LoanFactoryTerms {
+  fn loan_set_terms(&mut self, duration: u64, grace_period: u64, penalty_rate: u64);
+
+  fn loan_terms(&self) -> JsonLoanTerms;
+  fn loan_terms_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanTerms;
+}
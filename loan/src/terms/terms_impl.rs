@@ -0,0 +1,44 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::terms::LoanFactoryTerms;
+use crate::meta::JsonLoanTerms;
+use crate::event::LoanTermsUpdate;
+use near_sdk::env;
+
+const MAX_PENALTY_RATE: u64 = 100;
+
+impl LoanFactoryTerms for LoanFactory {
+  fn loan_set_terms(&mut self, duration: u64, grace_period: u64, penalty_rate: u64) {
+    self.assert_owner();
+
+    if duration == 0 {
+      env::panic_str("Duration should be positive");
+    }
+    if penalty_rate > MAX_PENALTY_RATE {
+      env::panic_str(&format!("Max penalty rate is {}", MAX_PENALTY_RATE));
+    }
+
+    self.loan_duration = duration;
+    self.grace_period = grace_period;
+    self.penalty_rate = penalty_rate;
+
+    LoanTermsUpdate {
+      duration: &duration,
+      grace_period: &grace_period,
+      penalty_rate: &penalty_rate,
+    }.emit();
+  }
+
+  fn loan_terms(&self) -> JsonLoanTerms {
+    self.enum_get_loan_terms(&self.internal_new_loan_terms(0, self.commission))
+  }
+
+  fn loan_terms_by_id(&self, token_id: TokenId, contract_id: ContractId) -> JsonLoanTerms {
+    let contract_token_id = self.internal_get_token_id(&contract_id, &token_id);
+
+    if self.loan_date_by_nft.get(&contract_token_id).is_none() {
+      env::panic_str("Not found loan");
+    }
+
+    self.enum_get_loan_terms(&self.internal_loan_terms(&contract_token_id))
+  }
+}
@@ -0,0 +1,75 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::tier::{Tier, TierRule};
+use near_contract_standards::non_fungible_token::Token;
+use near_sdk::serde_json::Value;
+use near_sdk::{env, ext_contract, PromiseResult};
+
+#[ext_contract(ext_nft_token)]
+pub trait NonFungibleTokenCore {
+  fn nft_token(&self, token_id: TokenId) -> Option<Token>;
+}
+
+/// `extra` of the metadata as `{"key": "value"}` or `{"attributes": [{"trait_type": "key", "value": "value"}]}`
+fn has_attribute(extra: &Value, key: &str, value: &str) -> bool {
+  // numbers and booleans are compared by their json value
+  let is_value = |el: &Value| el.as_str() == Some(value) || value.parse::<Value>().ok().as_ref() == Some(el);
+
+  extra.get(key).is_some_and(is_value)
+    || extra.get("attributes").and_then(Value::as_array).is_some_and(|attributes| {
+      attributes.iter().any(|attribute| {
+        attribute.get("trait_type").and_then(Value::as_str) == Some(key)
+          && attribute.get("value").is_some_and(is_value)
+      })
+    })
+}
+
+/// Whether `tier` matches the token, `None` for an attribute rule without the metadata
+fn tier_matches(tier: &Tier, token_id: &TokenId, extra: Option<&Value>) -> Option<bool> {
+  match &tier.rule {
+    TierRule::Tokens(token_ids) => Some(token_ids.contains(token_id)),
+    TierRule::Range { from, to } => Some(token_id.parse::<u64>().ok().is_some_and(|number| (*from..=*to).contains(&number))),
+    TierRule::Attribute { key, value } => extra.map(|extra| has_attribute(extra, key, value)),
+  }
+}
+
+/// Tier lookup without the token metadata
+pub(crate) enum TierMatch {
+  Tier(Tier),
+  /// an attribute tier comes before any token id match, `nft_token` decides
+  Metadata,
+  Collection,
+}
+
+impl LoanFactory {
+  /// First tier in list order matched by the token id, stops at an attribute tier
+  pub(crate) fn internal_tier_by_token_id(&self, contract_id: &ContractId, token_id: &TokenId) -> TierMatch {
+    for tier in self.tiers_by_contract.get(contract_id).unwrap_or_default() {
+      match tier_matches(&tier, token_id, None) {
+        Some(true) => return TierMatch::Tier(tier),
+        Some(false) => {}
+        None => return TierMatch::Metadata,
+      }
+    }
+
+    TierMatch::Collection
+  }
+
+  /// First tier in list order for the `nft_token` result of the previous promise, attribute rules
+  /// do not match a token without metadata
+  pub(crate) fn internal_tier_by_metadata(&self, contract_id: &ContractId, token_id: &TokenId) -> Option<Tier> {
+    let token = match env::promise_result(0) {
+      PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<Option<Token>>(&value).ok().flatten(),
+      _ => None,
+    };
+    let extra = token
+      .and_then(|token| token.metadata)
+      .and_then(|metadata| metadata.extra)
+      .and_then(|extra| near_sdk::serde_json::from_str::<Value>(&extra).ok())
+      .unwrap_or(Value::Null);
+
+    self.tiers_by_contract
+      .get(contract_id)?
+      .into_iter()
+      .find(|tier| tier_matches(tier, token_id, Some(&extra)) == Some(true))
+  }
+}
@@ -0,0 +1,6 @@
+mod tier_impl;
+mod tier;
+mod internal;
+
+pub use self::tier::{LoanFactoryTier, LoanFactoryTierResolver, Tier, TierRule};
+pub(crate) use self::internal::{ext_nft_token, TierMatch};
@@ -0,0 +1,39 @@
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::json_types::U128;
+use crate::base::{ContractId, TokenId};
+
+/// Tokens of a tier: explicit ids, a numeric id range or a metadata attribute
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum TierRule {
+  Tokens(Vec<TokenId>),
+  Range { from: u64, to: u64 },
+  Attribute { key: String, value: String },
+}
+
+/// Price and LTV percent of the tokens matching `rule`, the first matching tier in list order wins.
+/// Attribute rules are checked against the token metadata when the loan is taken
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Tier {
+  pub name: String,
+  pub rule: TierRule,
+  pub price: U128,
+  pub percent: u64,
+}
+
+pub trait LoanFactoryTier {
+  fn loan_nft_set_tiers(&mut self, contract_id: ContractId, tiers: Vec<Tier>);
+
+  fn loan_nft_tiers(&self, contract_id: ContractId) -> Vec<Tier>;
+  /// Tier decided by the token id, `None` when an attribute tier comes first and the metadata decides
+  fn loan_nft_tier_of(&self, contract_id: ContractId, token_id: TokenId) -> Option<Tier>;
+  fn loan_nft_tier_by_id(&self, token_id: TokenId, contract_id: ContractId) -> Option<String>;
+}
+
+pub trait LoanFactoryTierResolver {
+  fn loan_resolve_nft_tier(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId);
+}
@@ -0,0 +1,73 @@
+use crate::base::{LoanFactory, ContractId, TokenId};
+use crate::tier::{LoanFactoryTier, LoanFactoryTierResolver, Tier, TierMatch, TierRule};
+use crate::event::LoanWhitelistUpdateTiers;
+use near_sdk::{env, AccountId};
+
+const MAX_TIERS: usize = 20;
+
+impl LoanFactoryTier for LoanFactory {
+  fn loan_nft_set_tiers(&mut self, contract_id: ContractId, tiers: Vec<Tier>) {
+    self.assert_owner();
+
+    if tiers.len() > MAX_TIERS {
+      env::panic_str(&format!("Max tiers is {}", MAX_TIERS));
+    }
+
+    for (index, tier) in tiers.iter().enumerate() {
+      if tier.percent > 50 {
+        env::panic_str("Max percent is 50");
+      }
+      if tier.percent < 1 {
+        env::panic_str("Min percent is 1");
+      }
+      if tier.price.0 < 1 {
+        env::panic_str("Invalid price");
+      }
+      if let TierRule::Range { from, to } = tier.rule {
+        if from > to {
+          env::panic_str("Invalid tier range");
+        }
+      }
+      if tiers[..index].iter().any(|el| el.name == tier.name) {
+        env::panic_str("Tier names should be unique");
+      }
+    }
+
+    if tiers.is_empty() {
+      self.tiers_by_contract.remove(&contract_id);
+    } else {
+      self.tiers_by_contract.insert(&contract_id, &tiers);
+    }
+
+    LoanWhitelistUpdateTiers {
+      contract_id: &contract_id,
+      tiers: &tiers,
+    }.emit();
+  }
+
+  fn loan_nft_tiers(&self, contract_id: ContractId) -> Vec<Tier> {
+    self.tiers_by_contract.get(&contract_id).unwrap_or_default()
+  }
+
+  fn loan_nft_tier_of(&self, contract_id: ContractId, token_id: TokenId) -> Option<Tier> {
+    match self.internal_tier_by_token_id(&contract_id, &token_id) {
+      TierMatch::Tier(tier) => Some(tier),
+      TierMatch::Metadata | TierMatch::Collection => None,
+    }
+  }
+
+  fn loan_nft_tier_by_id(&self, token_id: TokenId, contract_id: ContractId) -> Option<String> {
+    self.tier_by_nft.get(&self.internal_get_token_id(&contract_id, &token_id))
+  }
+}
+
+impl LoanFactoryTierResolver for LoanFactory {
+  fn loan_resolve_nft_tier(&mut self, receiver_id: AccountId, contract_id: ContractId, token_id: TokenId) {
+    self.assert_nft_whitelist(&contract_id);
+
+    match self.internal_tier_by_metadata(&contract_id, &token_id) {
+      Some(tier) => self.internal_lend_nft(receiver_id, token_id, contract_id, tier.price.0, tier.percent, Some(tier.name)),
+      None => self.internal_loan_nft_by_collection(receiver_id, token_id, contract_id),
+    }
+  }
+}
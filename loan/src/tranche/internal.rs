@@ -0,0 +1,150 @@
+use crate::base::{ContractId, LoanFactory, TokenId};
+use crate::tranche::Tranche;
+use crate::event::LoanLoss;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+
+/// Yield cap of the junior tranche, senior cap is configured per pool
+pub(crate) const JUNIOR_MAX_APR: u64 = 28;
+
+impl LoanFactory {
+  /// Accounts without a tranche are junior, as before tranches were introduced
+  pub(crate) fn internal_tranche_of(&self, account_id: &AccountId) -> Tranche {
+    self.tranche_by_account.get(account_id).unwrap_or(Tranche::Junior)
+  }
+
+  /// Keeps the current tranche when `tranche` is empty, an account can switch only without shares
+  pub(crate) fn internal_set_tranche(&mut self, account_id: &AccountId, tranche: Option<Tranche>) {
+    if let Some(tranche) = tranche {
+      if self.internal_shares_of(account_id) > 0 && self.internal_tranche_of(account_id) != tranche {
+        env::panic_str("Account is in another tranche");
+      }
+
+      self.tranche_by_account.insert(account_id, &tranche);
+    }
+  }
+
+  pub(crate) fn internal_tranche_balance(&self, tranche: &Tranche) -> Balance {
+    match tranche {
+      Tranche::Senior => self.senior_balance.0,
+      Tranche::Junior => self.total_balance.0 - self.senior_balance.0,
+    }
+  }
+
+  pub(crate) fn internal_tranche_shares(&self, tranche: &Tranche) -> Balance {
+    match tranche {
+      Tranche::Senior => self.senior_shares.0,
+      Tranche::Junior => self.total_shares.0 - self.senior_shares.0,
+    }
+  }
+
+  pub(crate) fn internal_tranche_epoch(&self, tranche: &Tranche) -> u64 {
+    match tranche {
+      Tranche::Senior => self.senior_epoch,
+      Tranche::Junior => self.junior_epoch,
+    }
+  }
+
+  /// Shares minted before the tranche was wiped out are worth nothing
+  pub(crate) fn internal_is_stale_shares(&self, account_id: &AccountId) -> bool {
+    let tranche = self.internal_tranche_of(account_id);
+
+    self.share_epoch_by_account.get(account_id).unwrap_or(0) != self.internal_tranche_epoch(&tranche)
+  }
+
+  /// Drops shares and locks left from a wiped out epoch, the account starts over in its tranche
+  pub(crate) fn internal_sync_share_epoch(&mut self, account_id: &AccountId) {
+    if !self.internal_is_stale_shares(account_id) {
+      return;
+    }
+
+    let tranche = self.internal_tranche_of(account_id);
+
+    self.shares_by_account.remove(account_id);
+    self.locks_by_account.remove(account_id);
+    self.share_epoch_by_account.insert(account_id, &self.internal_tranche_epoch(&tranche));
+  }
+
+  pub(crate) fn internal_set_shares(&mut self, account_id: &AccountId, shares: Balance) {
+    let tranche = self.internal_tranche_of(account_id);
+
+    self.shares_by_account.insert(account_id, &U128::from(shares));
+    self.share_epoch_by_account.insert(account_id, &self.internal_tranche_epoch(&tranche));
+  }
+
+  /// A tranche without balance writes off its shares and lock boost, the next deposit mints 1:1
+  pub(crate) fn internal_rebase_tranche(&mut self, tranche: &Tranche) {
+    let shares = self.internal_tranche_shares(tranche);
+    let boost = self.internal_tranche_boost(tranche);
+
+    self.internal_sub_tranche(tranche, 0, shares);
+    self.internal_change_boost(tranche, 0, boost);
+
+    match tranche {
+      Tranche::Senior => self.senior_epoch += 1,
+      Tranche::Junior => self.junior_epoch += 1,
+    }
+  }
+
+  pub(crate) fn internal_tranche_max_apr(&self, tranche: &Tranche) -> u64 {
+    match tranche {
+      Tranche::Senior => self.senior_max_apr,
+      Tranche::Junior => JUNIOR_MAX_APR,
+    }
+  }
+
+  /// Part of the rewards pool for the tranche, junior balance is weighted by `junior_fee_multiplier`
+  pub(crate) fn internal_tranche_rewards(&self, tranche: &Tranche) -> Balance {
+    let senior_weight = self.internal_tranche_balance(&Tranche::Senior);
+    let junior_weight = self.internal_tranche_balance(&Tranche::Junior) * self.junior_fee_multiplier as u128;
+    let total_weight = senior_weight + junior_weight;
+
+    if total_weight == 0 {
+      return 0;
+    }
+
+    let weight = match tranche {
+      Tranche::Senior => senior_weight,
+      Tranche::Junior => junior_weight,
+    };
+
+    self.total_rewards_pool.0 * weight / total_weight
+  }
+
+  pub(crate) fn internal_add_tranche(&mut self, tranche: &Tranche, amount: Balance, num_shares: Balance) {
+    self.total_balance = U128::from(self.total_balance.0 + amount);
+    self.total_shares = U128::from(self.total_shares.0 + num_shares);
+
+    if *tranche == Tranche::Senior {
+      self.senior_balance = U128::from(self.senior_balance.0 + amount);
+      self.senior_shares = U128::from(self.senior_shares.0 + num_shares);
+    }
+  }
+
+  pub(crate) fn internal_sub_tranche(&mut self, tranche: &Tranche, amount: Balance, num_shares: Balance) {
+    self.total_balance = U128::from(self.total_balance.0 - amount);
+    self.total_shares = U128::from(self.total_shares.0 - num_shares);
+
+    if *tranche == Tranche::Senior {
+      self.senior_balance = U128::from(self.senior_balance.0 - amount);
+      self.senior_shares = U128::from(self.senior_shares.0 - num_shares);
+    }
+  }
+
+  /// Writes off a defaulted loan, junior balance absorbs the loss first
+  pub(crate) fn internal_allocate_loss(&mut self, contract_id: &ContractId, token_id: &TokenId, loss: Balance) {
+    let junior_loss = std::cmp::min(loss, self.internal_tranche_balance(&Tranche::Junior));
+    let senior_loss = loss - junior_loss;
+
+    self.total_loan = U128::from(self.total_loan.0 - loss);
+    self.total_balance = U128::from(self.total_balance.0 - loss);
+    self.senior_balance = U128::from(self.senior_balance.0 - senior_loss);
+
+    LoanLoss {
+      contract_id,
+      token_id,
+      junior_loss: &U128::from(junior_loss),
+      senior_loss: &U128::from(senior_loss),
+    }.emit();
+  }
+}
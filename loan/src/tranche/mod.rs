@@ -0,0 +1,5 @@
+mod tranche_impl;
+mod tranche;
+mod internal;
+
+pub use self::tranche::{LoanFactoryTranche, Tranche};
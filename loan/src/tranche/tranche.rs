@@ -0,0 +1,23 @@
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::meta::JsonTrancheConfig;
+
+/// Junior takes defaults first and a larger part of fees, senior has a capped yield
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum Tranche {
+  Senior,
+  Junior,
+}
+
+pub trait LoanFactoryTranche {
+  fn loan_set_tranche_config(&mut self, senior_max_apr: u64, junior_fee_multiplier: u64);
+
+  fn loan_tranche_config(&self) -> JsonTrancheConfig;
+  fn loan_tranche_of(&self, account_id: AccountId) -> Tranche;
+  fn loan_tranche_balance(&self, tranche: Tranche) -> U128;
+  fn loan_tranche_shares(&self, tranche: Tranche) -> U128;
+}
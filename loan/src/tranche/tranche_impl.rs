@@ -0,0 +1,48 @@
+use crate::base::LoanFactory;
+use crate::tranche::{LoanFactoryTranche, Tranche};
+use crate::tranche::internal::JUNIOR_MAX_APR;
+use crate::meta::JsonTrancheConfig;
+use crate::event::LoanTrancheConfigUpdate;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId};
+
+impl LoanFactoryTranche for LoanFactory {
+  fn loan_set_tranche_config(&mut self, senior_max_apr: u64, junior_fee_multiplier: u64) {
+    self.assert_owner();
+
+    if !(1..JUNIOR_MAX_APR).contains(&senior_max_apr) {
+      env::panic_str(&format!("Senior apr should be from 1 to {}", JUNIOR_MAX_APR - 1));
+    }
+    if junior_fee_multiplier < 1 {
+      env::panic_str("Min junior fee multiplier is 1");
+    }
+
+    self.senior_max_apr = senior_max_apr;
+    self.junior_fee_multiplier = junior_fee_multiplier;
+
+    LoanTrancheConfigUpdate {
+      senior_max_apr: &senior_max_apr,
+      junior_fee_multiplier: &junior_fee_multiplier,
+    }.emit();
+  }
+
+  fn loan_tranche_config(&self) -> JsonTrancheConfig {
+    JsonTrancheConfig {
+      senior_max_apr: self.senior_max_apr,
+      junior_max_apr: JUNIOR_MAX_APR,
+      junior_fee_multiplier: self.junior_fee_multiplier,
+    }
+  }
+
+  fn loan_tranche_of(&self, account_id: AccountId) -> Tranche {
+    self.internal_tranche_of(&account_id)
+  }
+
+  fn loan_tranche_balance(&self, tranche: Tranche) -> U128 {
+    U128::from(self.internal_tranche_balance(&tranche))
+  }
+
+  fn loan_tranche_shares(&self, tranche: Tranche) -> U128 {
+    U128::from(self.internal_tranche_shares(&tranche))
+  }
+}
@@ -3,9 +3,34 @@ use near_sdk::{env, Balance};
 pub(crate) fn date_now() -> u64 {
     env::block_timestamp() / 1000000
 }
+/// Allowed clock difference of off-chain timestamps, ms
+pub(crate) const CLOCK_SKEW: u64 = 60000; // 1 min
+
+/// Off-chain `timestamp` is not older than `max_age` and not ahead of `now` by more than the clock skew
+pub(crate) fn is_fresh(timestamp: u64, now: u64, max_age: u64) -> bool {
+  timestamp <= now + CLOCK_SKEW && now.saturating_sub(timestamp) <= max_age
+}
 pub(crate) fn method_disabled() {
   env::panic_str("Method disabled");
 }
 pub fn yton(yocto_amount: Balance) -> Balance {
   (yocto_amount + (5 * 10u128.pow(23))) / 10u128.pow(24)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn is_fresh_checks_age() {
+    assert!(is_fresh(1000, 1500, 500));
+    assert!(!is_fresh(1000, 1501, 500));
+  }
+
+  #[test]
+  fn is_fresh_rejects_future_timestamp() {
+    assert!(is_fresh(1000 + CLOCK_SKEW, 1000, 500));
+    assert!(!is_fresh(1001 + CLOCK_SKEW, 1000, 500));
+    assert!(!is_fresh(u64::MAX / 2, 1000, u64::MAX));
+  }
+}
@@ -1,5 +1,7 @@
-use crate::base::{ContractId, LoanFactory};
-use near_sdk::env;
+use crate::base::{ContractId, LoanFactory, TokenId};
+use crate::meta::JsonExposure;
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
 
 impl LoanFactory {
     pub(crate) fn assert_nft_whitelist(&self, contract_id: &ContractId) {
@@ -16,4 +18,50 @@ impl LoanFactory {
 
       exists && price && percent
     }
+
+    pub(crate) fn assert_nft_exposure(&self, contract_id: &ContractId, loan_amount: Balance) {
+        let cap = match self.exposure_cap_by_contract.get(contract_id) {
+            Some(cap) => cap,
+            None => return,
+        };
+        let next = self.exposure_by_contract.get(contract_id).unwrap_or(0) + loan_amount;
+
+        if cap.max_principal.is_some_and(|max_principal| next > max_principal) {
+            env::panic_str("Collection exposure cap is reached");
+        }
+        if cap.max_share.is_some_and(|max_share| next > self.total_balance.0 * max_share as u128 / 100) {
+            env::panic_str("Collection share of the pool is reached");
+        }
+    }
+
+    fn internal_contract_of_token(contract_token_id: &TokenId) -> ContractId {
+        let arr = contract_token_id.split("||").collect::<Vec<&str>>();
+
+        AccountId::new_unchecked(arr[0].to_string())
+    }
+
+    pub(crate) fn internal_increase_exposure(&mut self, contract_token_id: &TokenId, amount: Balance) {
+        let contract_id = LoanFactory::internal_contract_of_token(contract_token_id);
+        let current = self.exposure_by_contract.get(&contract_id).unwrap_or(0);
+
+        self.exposure_by_contract.insert(&contract_id, &(current + amount));
+    }
+    pub(crate) fn internal_decrease_exposure(&mut self, contract_token_id: &TokenId, amount: Balance) {
+        let contract_id = LoanFactory::internal_contract_of_token(contract_token_id);
+        // loans made before exposure tracking are not counted
+        let current = self.exposure_by_contract.get(&contract_id).unwrap_or(0);
+
+        self.exposure_by_contract.insert(&contract_id, &current.saturating_sub(amount));
+    }
+
+    pub(crate) fn enum_get_exposure(&self, contract_id: &ContractId) -> JsonExposure {
+        let cap = self.exposure_cap_by_contract.get(contract_id);
+
+        JsonExposure {
+            contract_id: contract_id.clone(),
+            principal: U128::from(self.exposure_by_contract.get(contract_id).unwrap_or(0)),
+            max_principal: cap.as_ref().and_then(|cap| cap.max_principal).map(U128::from),
+            max_share: cap.and_then(|cap| cap.max_share),
+        }
+    }
 }
@@ -2,4 +2,4 @@ mod whitelist_impl;
 mod whitelist;
 mod internal;
 
-pub use self::whitelist::{ LoanFactoryWhitelist };
+pub use self::whitelist::{ LoanFactoryWhitelist, ExposureCap };
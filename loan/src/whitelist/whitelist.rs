@@ -1,8 +1,23 @@
 use crate::base::ContractId;
+use near_sdk::Balance;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use crate::meta::JsonExposure;
+
+/// Limits of outstanding principal for a collection
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ExposureCap {
+    pub max_principal: Option<Balance>,
+    pub max_share: Option<u64>,
+}
 
 pub trait LoanFactoryWhitelist {
     fn loan_nft_whitelist_add(&mut self, contract_id: ContractId);
     fn loan_nft_whitelist_remove(&mut self, contract_id: ContractId);
     fn loan_nft_whitelist(&self) -> Vec<ContractId>;
     fn loan_nft_is_whitelist(&self, contract_id: ContractId) -> bool;
+
+    fn loan_nft_set_exposure_cap(&mut self, contract_id: ContractId, max_principal: Option<U128>, max_share: Option<u64>);
+    fn loan_nft_exposure(&self, contract_id: ContractId) -> JsonExposure;
+    fn loan_nft_exposures(&self) -> Vec<JsonExposure>;
 }
@@ -1,7 +1,9 @@
 use crate::base::{LoanFactory, ContractId};
-use crate::whitelist::LoanFactoryWhitelist;
+use crate::whitelist::{LoanFactoryWhitelist, ExposureCap};
+use crate::meta::JsonExposure;
 use near_sdk::env;
-use crate::event::{LoanWhitelistAdd, LoanWhitelistRemove};
+use near_sdk::json_types::U128;
+use crate::event::{LoanWhitelistAdd, LoanWhitelistRemove, LoanWhitelistUpdateExposureCap};
 
 impl LoanFactoryWhitelist for LoanFactory {
     fn loan_nft_whitelist_add(&mut self, contract_id: ContractId) {
@@ -28,6 +30,10 @@ impl LoanFactoryWhitelist for LoanFactory {
         self.whitelist.remove(&contract_id);
         self.price_by_contract.remove(&contract_id);
         self.percent_by_contract.remove(&contract_id);
+        self.oracle_by_contract.remove(&contract_id);
+        self.price_feed_by_contract.remove(&contract_id);
+        self.twap_window_by_contract.remove(&contract_id);
+        self.tiers_by_contract.remove(&contract_id);
 
       LoanWhitelistRemove {
         contract_id: &contract_id,
@@ -45,4 +51,36 @@ impl LoanFactoryWhitelist for LoanFactory {
     fn loan_nft_is_whitelist(&self, contract_id: ContractId) -> bool {
         self.internal_is_nft_whitelist(&contract_id)
     }
+
+    fn loan_nft_set_exposure_cap(&mut self, contract_id: ContractId, max_principal: Option<U128>, max_share: Option<u64>) {
+        self.assert_owner();
+
+        if max_share.is_some_and(|max_share| !(1..=100).contains(&max_share)) {
+            env::panic_str("Max share should be from 1 to 100");
+        }
+
+        if max_principal.is_none() && max_share.is_none() {
+            self.exposure_cap_by_contract.remove(&contract_id);
+        } else {
+            self.exposure_cap_by_contract.insert(&contract_id, &ExposureCap {
+                max_principal: max_principal.map(|el| el.0),
+                max_share,
+            });
+        }
+
+        LoanWhitelistUpdateExposureCap {
+            contract_id: &contract_id,
+            max_principal: max_principal.as_ref(),
+            max_share: max_share.as_ref(),
+        }.emit();
+    }
+    fn loan_nft_exposure(&self, contract_id: ContractId) -> JsonExposure {
+        self.enum_get_exposure(&contract_id)
+    }
+    fn loan_nft_exposures(&self) -> Vec<JsonExposure> {
+        self.whitelist
+            .keys()
+            .map(|contract_id| self.enum_get_exposure(contract_id))
+            .collect()
+    }
 }
@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+set -e
+
+sh ./build.sh
+
+near dev-deploy ../res/oracle.wasm
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+OWNER_ID="$CONTRACT_NAME"
+near call $CONTRACT_NAME new --accountId $CONTRACT_NAME "{ \"owner_id\": \"$OWNER_ID\" }"
@@ -0,0 +1,7 @@
+#!/bin/bash
+
+if [ -z "$KEEP_NAMES" ]; then
+  export RUSTFLAGS='-C link-arg=-s'
+else
+  export RUSTFLAGS=''
+fi
\ No newline at end of file
@@ -0,0 +1,4 @@
+#!/bin/bash
+source neardev/dev-account.env
+NFT_CONTRACT="dev-1648577859565-13862973208014"
+near call $CONTRACT_NAME oracle_set_price --accountId $CONTRACT_NAME "{ \"contract_id\": \"$NFT_CONTRACT\", \"price\": \"2000000000000000000000000\" }"
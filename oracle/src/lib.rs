@@ -0,0 +1,64 @@
+/*!
+Mock price oracle for the loan contract.
+NOTES:
+  - Stand-in for a real collection price feed, prices are set by the owner by hand.
+  - `oracle_price` is the only method the loan contract calls.
+  - Timestamps are in milliseconds, same as `date_now` of the loan contract.
+*/
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen, require, AccountId, BorshStorageKey, PanicOnDefault};
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceQuote {
+  pub price: U128,
+  pub timestamp: u64,
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+  owner_id: AccountId,
+  price_by_contract: LookupMap<AccountId, PriceQuote>,
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+enum StorageKey {
+  PriceByContract,
+}
+
+#[near_bindgen]
+impl Contract {
+  #[init]
+  pub fn new(owner_id: AccountId) -> Self {
+    require!(!env::state_exists(), "Already initialized");
+
+    Self {
+      owner_id,
+      price_by_contract: LookupMap::new(StorageKey::PriceByContract),
+    }
+  }
+
+  /// `timestamp` defaults to the current block, pass an old one to emulate a stale quote
+  pub fn oracle_set_price(&mut self, contract_id: AccountId, price: U128, timestamp: Option<u64>) {
+    require!(env::predecessor_account_id() == self.owner_id, "Unauthorized");
+
+    self.price_by_contract.insert(&contract_id, &PriceQuote {
+      price,
+      timestamp: timestamp.unwrap_or_else(|| env::block_timestamp() / 1000000),
+    });
+  }
+
+  pub fn oracle_remove_price(&mut self, contract_id: AccountId) {
+    require!(env::predecessor_account_id() == self.owner_id, "Unauthorized");
+
+    self.price_by_contract.remove(&contract_id);
+  }
+
+  pub fn oracle_price(&self, contract_id: AccountId) -> Option<PriceQuote> {
+    self.price_by_contract.get(&contract_id)
+  }
+}